@@ -0,0 +1,87 @@
+use std::{
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use clap::Subcommand;
+use edc_connector_client::EdcConnectorClient;
+use serde::de::DeserializeOwned;
+
+use crate::{error::CliError, output::Output};
+
+mod agreement;
+mod asset;
+mod catalog;
+mod contract_definition;
+mod dataplane;
+mod edr;
+mod negotiation;
+mod policy;
+mod transfer;
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Manage assets.
+    #[command(subcommand)]
+    Asset(asset::AssetCommand),
+    /// Manage policy definitions.
+    #[command(subcommand)]
+    Policy(policy::PolicyCommand),
+    /// Manage contract definitions.
+    #[command(subcommand)]
+    ContractDefinition(contract_definition::ContractDefinitionCommand),
+    /// Request catalogs and datasets from other connectors.
+    #[command(subcommand)]
+    Catalog(catalog::CatalogCommand),
+    /// Inspect contract negotiations.
+    #[command(subcommand)]
+    Negotiation(negotiation::NegotiationCommand),
+    /// Inspect contract agreements.
+    #[command(subcommand)]
+    Agreement(agreement::AgreementCommand),
+    /// Inspect and control transfer processes.
+    #[command(subcommand)]
+    Transfer(transfer::TransferCommand),
+    /// Inspect endpoint data references.
+    #[command(subcommand)]
+    Edr(edr::EdrCommand),
+    /// Inspect data planes.
+    #[command(subcommand)]
+    Dataplane(dataplane::DataPlaneCommand),
+}
+
+impl Command {
+    pub async fn run(self, client: &EdcConnectorClient, output: Output) -> Result<(), CliError> {
+        match self {
+            Command::Asset(command) => command.run(client, output).await,
+            Command::Policy(command) => command.run(client, output).await,
+            Command::ContractDefinition(command) => command.run(client, output).await,
+            Command::Catalog(command) => command.run(client, output).await,
+            Command::Negotiation(command) => command.run(client, output).await,
+            Command::Agreement(command) => command.run(client, output).await,
+            Command::Transfer(command) => command.run(client, output).await,
+            Command::Edr(command) => command.run(client, output).await,
+            Command::Dataplane(command) => command.run(client, output).await,
+        }
+    }
+}
+
+/// Reads a resource from a JSON file, `-` reads from stdin.
+fn read_json<T: DeserializeOwned>(path: &Path) -> Result<T, CliError> {
+    let content = if path == Path::new("-") {
+        let mut content = String::new();
+        io::stdin().read_to_string(&mut content).map(|_| content)
+    } else {
+        fs::read_to_string(path)
+    }
+    .map_err(|source| CliError::Read {
+        path: path.to_path_buf(),
+        source,
+    })?;
+
+    serde_json::from_str(&content).map_err(|source| CliError::Json {
+        path: PathBuf::from(path),
+        source,
+    })
+}
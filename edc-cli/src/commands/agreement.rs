@@ -0,0 +1,51 @@
+use clap::Subcommand;
+use edc_connector_client::{types::contract_agreement::ContractAgreement, EdcConnectorClient};
+
+use crate::{
+    error::CliError,
+    output::{Output, Tabular},
+    query::QueryArgs,
+};
+
+#[derive(Debug, Subcommand)]
+pub enum AgreementCommand {
+    /// List the contract agreements matching the query.
+    List(QueryArgs),
+    /// Show a contract agreement.
+    Get { id: String },
+    /// Show the negotiation of a contract agreement.
+    Negotiation { id: String },
+}
+
+impl AgreementCommand {
+    pub async fn run(self, client: &EdcConnectorClient, output: Output) -> Result<(), CliError> {
+        let api = client.contract_agreements();
+
+        match self {
+            AgreementCommand::List(query) => {
+                let agreements = if query.is_paged() {
+                    api.query(query.to_query()).await?
+                } else {
+                    api.query_all(query.to_query()).await?
+                };
+                output.print(&agreements[..])
+            }
+            AgreementCommand::Get { id } => output.print(&api.get(&id).await?),
+            AgreementCommand::Negotiation { id } => output.print(&api.get_negotiation(&id).await?),
+        }
+    }
+}
+
+impl Tabular for ContractAgreement {
+    const HEADERS: &'static [&'static str] = &["ID", "ASSET", "PROVIDER", "CONSUMER", "SIGNED AT"];
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        vec![vec![
+            self.id().to_string(),
+            self.asset_id().to_string(),
+            self.provider_id().to_string(),
+            self.consumer_id().to_string(),
+            self.contract_signing_date().to_string(),
+        ]]
+    }
+}
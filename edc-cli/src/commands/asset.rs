@@ -0,0 +1,78 @@
+use std::path::PathBuf;
+
+use clap::Subcommand;
+use edc_connector_client::{
+    types::asset::{Asset, NewAsset},
+    EdcConnectorClient,
+};
+
+use crate::{
+    error::CliError,
+    output::{Created, Output, Tabular},
+    query::QueryArgs,
+};
+
+use super::read_json;
+
+#[derive(Debug, Subcommand)]
+pub enum AssetCommand {
+    /// List the assets matching the query.
+    List(QueryArgs),
+    /// Show an asset.
+    Get { id: String },
+    /// Create an asset from a JSON file.
+    Create {
+        #[arg(long, short)]
+        file: PathBuf,
+    },
+    /// Replace an asset with the one in a JSON file.
+    Update {
+        #[arg(long, short)]
+        file: PathBuf,
+    },
+    /// Delete an asset.
+    Delete { id: String },
+}
+
+impl AssetCommand {
+    pub async fn run(self, client: &EdcConnectorClient, output: Output) -> Result<(), CliError> {
+        let api = client.assets();
+
+        match self {
+            AssetCommand::List(query) => {
+                let assets = if query.is_paged() {
+                    api.query(query.to_query()).await?
+                } else {
+                    api.query_all(query.to_query()).await?
+                };
+                output.print(&assets[..])
+            }
+            AssetCommand::Get { id } => output.print(&api.get(&id).await?),
+            AssetCommand::Create { file } => {
+                let response = api.create(&read_json::<NewAsset>(&file)?).await?;
+                output.print(&Created { id: response.id() })
+            }
+            AssetCommand::Update { file } => Ok(api.update(&read_json(&file)?).await?),
+            AssetCommand::Delete { id } => Ok(api.delete(&id).await?),
+        }
+    }
+}
+
+impl Tabular for Asset {
+    const HEADERS: &'static [&'static str] = &["ID", "NAME", "TYPE"];
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        vec![vec![
+            self.id().to_string(),
+            self.property::<String>("name")
+                .ok()
+                .flatten()
+                .unwrap_or_default(),
+            self.data_address()
+                .property::<String>("type")
+                .ok()
+                .flatten()
+                .unwrap_or_default(),
+        ]]
+    }
+}
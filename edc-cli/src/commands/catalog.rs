@@ -0,0 +1,110 @@
+use clap::{Args, Subcommand};
+use edc_connector_client::{
+    types::catalog::{Catalog, CatalogRequest, Dataset, DatasetRequest},
+    EdcConnectorClient, DATASPACE_PROTOCOL,
+};
+
+use crate::{
+    error::CliError,
+    output::{Output, Tabular},
+    query::QueryArgs,
+};
+
+#[derive(Debug, Subcommand)]
+pub enum CatalogCommand {
+    /// Request the catalog of a connector.
+    Request {
+        #[command(flatten)]
+        counter_party: CounterPartyArgs,
+        /// Participant id of the connector.
+        #[arg(long)]
+        counter_party_id: Option<String>,
+        #[command(flatten)]
+        query: QueryArgs,
+        /// Page through the whole catalog, listing the datasets of every page
+        /// and of the sub-catalogs.
+        #[arg(long)]
+        all: bool,
+    },
+    /// Request a dataset from the catalog of a connector.
+    Dataset {
+        id: String,
+        #[command(flatten)]
+        counter_party: CounterPartyArgs,
+    },
+}
+
+#[derive(Debug, Args)]
+pub struct CounterPartyArgs {
+    /// Protocol address of the connector, e.g. http://provider:19194/protocol.
+    #[arg(long)]
+    counter_party_address: String,
+    #[arg(long, default_value = DATASPACE_PROTOCOL)]
+    protocol: String,
+}
+
+impl CatalogCommand {
+    pub async fn run(self, client: &EdcConnectorClient, output: Output) -> Result<(), CliError> {
+        let api = client.catalogue();
+
+        match self {
+            CatalogCommand::Request {
+                counter_party,
+                counter_party_id,
+                query,
+                all,
+            } => {
+                let request = CatalogRequest::builder()
+                    .protocol(&counter_party.protocol)
+                    .counter_party_address(&counter_party.counter_party_address)
+                    .query_spec(query.to_query());
+
+                let request = match &counter_party_id {
+                    Some(id) => request.counter_party_id(id),
+                    None => request,
+                };
+
+                let request = request.build()?;
+
+                if all {
+                    output.print(&api.request_all(&request).await?[..])
+                } else {
+                    output.print(&api.request(&request).await?)
+                }
+            }
+            CatalogCommand::Dataset { id, counter_party } => {
+                let request = DatasetRequest::builder()
+                    .id(&id)
+                    .protocol(&counter_party.protocol)
+                    .counter_party_address(&counter_party.counter_party_address)
+                    .build()?;
+
+                output.print(&api.dataset(&request).await?)
+            }
+        }
+    }
+}
+
+impl Tabular for Catalog {
+    const HEADERS: &'static [&'static str] = Dataset::HEADERS;
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        self.all_datasets().flat_map(Tabular::rows).collect()
+    }
+}
+
+impl Tabular for Dataset {
+    const HEADERS: &'static [&'static str] = &["DATASET", "OFFERS", "TRANSFER TYPES"];
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        let offers = self
+            .offers()
+            .iter()
+            .filter_map(|offer| offer.id().cloned())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let transfer_types = self.transfer_types().collect::<Vec<_>>().join(", ");
+
+        vec![vec![self.id().to_string(), offers, transfer_types]]
+    }
+}
@@ -0,0 +1,91 @@
+use std::path::PathBuf;
+
+use clap::Subcommand;
+use edc_connector_client::{
+    types::contract_definition::{ContractDefinition, NewContractDefinition},
+    EdcConnectorClient,
+};
+
+use crate::{
+    error::CliError,
+    output::{text, Created, Output, Tabular},
+    query::QueryArgs,
+};
+
+use super::read_json;
+
+#[derive(Debug, Subcommand)]
+pub enum ContractDefinitionCommand {
+    /// List the contract definitions matching the query.
+    List(QueryArgs),
+    /// Show a contract definition.
+    Get { id: String },
+    /// Create a contract definition from a JSON file.
+    Create {
+        #[arg(long, short)]
+        file: PathBuf,
+    },
+    /// Replace a contract definition with the one in a JSON file.
+    Update {
+        #[arg(long, short)]
+        file: PathBuf,
+    },
+    /// Delete a contract definition.
+    Delete { id: String },
+}
+
+impl ContractDefinitionCommand {
+    pub async fn run(self, client: &EdcConnectorClient, output: Output) -> Result<(), CliError> {
+        let api = client.contract_definitions();
+
+        match self {
+            ContractDefinitionCommand::List(query) => {
+                let definitions = if query.is_paged() {
+                    api.query(query.to_query()).await?
+                } else {
+                    api.query_all(query.to_query()).await?
+                };
+                output.print(&definitions[..])
+            }
+            ContractDefinitionCommand::Get { id } => output.print(&api.get(&id).await?),
+            ContractDefinitionCommand::Create { file } => {
+                let response = api
+                    .create(&read_json::<NewContractDefinition>(&file)?)
+                    .await?;
+                output.print(&Created { id: response.id() })
+            }
+            ContractDefinitionCommand::Update { file } => {
+                Ok(api.update(&read_json(&file)?).await?)
+            }
+            ContractDefinitionCommand::Delete { id } => Ok(api.delete(&id).await?),
+        }
+    }
+}
+
+impl Tabular for ContractDefinition {
+    const HEADERS: &'static [&'static str] =
+        &["ID", "ACCESS POLICY", "CONTRACT POLICY", "ASSETS SELECTOR"];
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        let selector = self
+            .assets_selector()
+            .iter()
+            .map(|criterion| {
+                format!(
+                    "{} {} {}",
+                    criterion.operand_left(),
+                    criterion.operator(),
+                    text(criterion.operand_right())
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        vec![vec![
+            self.id().to_string(),
+            self.access_policy_id().to_string(),
+            self.contract_policy_id().to_string(),
+            selector,
+        ]]
+    }
+}
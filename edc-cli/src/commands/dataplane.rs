@@ -0,0 +1,34 @@
+use clap::Subcommand;
+use edc_connector_client::{types::dataplane::DataPlaneInstance, EdcConnectorClient};
+
+use crate::{
+    error::CliError,
+    output::{text, Output, Tabular},
+};
+
+#[derive(Debug, Subcommand)]
+pub enum DataPlaneCommand {
+    /// List the registered data planes.
+    List,
+}
+
+impl DataPlaneCommand {
+    pub async fn run(self, client: &EdcConnectorClient, output: Output) -> Result<(), CliError> {
+        match self {
+            DataPlaneCommand::List => output.print(&client.data_planes().list().await?[..]),
+        }
+    }
+}
+
+impl Tabular for DataPlaneInstance {
+    const HEADERS: &'static [&'static str] = &["ID", "URL", "STATE", "TRANSFER TYPES"];
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        vec![vec![
+            self.id().to_string(),
+            self.url().to_string(),
+            text(self.state()),
+            self.allowed_transfer_types().join(", "),
+        ]]
+    }
+}
@@ -0,0 +1,94 @@
+use clap::Subcommand;
+use edc_connector_client::{
+    types::{data_address::DataAddress, edr::EndpointDataReferenceEntry},
+    EdcConnectorClient,
+};
+use serde_json::Value;
+
+use crate::{
+    error::CliError,
+    output::{text, Output, Tabular},
+    query::QueryArgs,
+};
+
+#[derive(Debug, Subcommand)]
+pub enum EdrCommand {
+    /// List the EDR entries matching the query.
+    List(QueryArgs),
+    /// Show the EDR entry of a transfer process.
+    Get { transfer_process_id: String },
+    /// Show the data address of an EDR.
+    DataAddress {
+        transfer_process_id: String,
+        /// Refresh the EDR when expired.
+        #[arg(long)]
+        auto_refresh: bool,
+    },
+    /// Refresh an EDR and show the new data address.
+    Refresh { transfer_process_id: String },
+    /// Delete an EDR.
+    Delete { transfer_process_id: String },
+}
+
+impl EdrCommand {
+    pub async fn run(self, client: &EdcConnectorClient, output: Output) -> Result<(), CliError> {
+        let api = client.edrs();
+
+        match self {
+            EdrCommand::List(query) => {
+                let entries = if query.is_paged() {
+                    api.query(query.to_query()).await?
+                } else {
+                    api.query_all(query.to_query()).await?
+                };
+                output.print(&entries[..])
+            }
+            EdrCommand::Get {
+                transfer_process_id,
+            } => output.print(&api.get_entry(&transfer_process_id).await?),
+            EdrCommand::DataAddress {
+                transfer_process_id,
+                auto_refresh,
+            } => {
+                let data_address = api
+                    .get_data_address_with_auto_refresh(&transfer_process_id, auto_refresh)
+                    .await?;
+                output.print(&data_address)
+            }
+            EdrCommand::Refresh {
+                transfer_process_id,
+            } => output.print(&api.refresh(&transfer_process_id).await?),
+            EdrCommand::Delete {
+                transfer_process_id,
+            } => Ok(api.delete(&transfer_process_id).await?),
+        }
+    }
+}
+
+impl Tabular for EndpointDataReferenceEntry {
+    const HEADERS: &'static [&'static str] =
+        &["TRANSFER PROCESS", "ASSET", "AGREEMENT", "PROVIDER"];
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        vec![vec![
+            self.transfer_process_id().to_string(),
+            self.asset_id().to_string(),
+            self.agreement_id().to_string(),
+            self.provider_id().to_string(),
+        ]]
+    }
+}
+
+impl Tabular for DataAddress {
+    const HEADERS: &'static [&'static str] = &["PROPERTY", "VALUE"];
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        match serde_json::to_value(self) {
+            Ok(Value::Object(properties)) => properties
+                .iter()
+                .map(|(property, value)| vec![property.clone(), text(value)])
+                .collect(),
+            _ => vec![],
+        }
+    }
+}
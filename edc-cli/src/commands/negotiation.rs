@@ -0,0 +1,58 @@
+use clap::Subcommand;
+use edc_connector_client::{types::contract_negotiation::ContractNegotiation, EdcConnectorClient};
+
+use crate::{
+    error::CliError,
+    output::{text, Output, Tabular},
+    query::QueryArgs,
+};
+
+#[derive(Debug, Subcommand)]
+pub enum NegotiationCommand {
+    /// List the contract negotiations matching the query.
+    List(QueryArgs),
+    /// Show a contract negotiation.
+    Get { id: String },
+    /// Show the agreement of a contract negotiation.
+    Agreement { id: String },
+    /// Terminate a contract negotiation.
+    Terminate {
+        id: String,
+        #[arg(long)]
+        reason: String,
+    },
+}
+
+impl NegotiationCommand {
+    pub async fn run(self, client: &EdcConnectorClient, output: Output) -> Result<(), CliError> {
+        let api = client.contract_negotiations();
+
+        match self {
+            NegotiationCommand::List(query) => {
+                let negotiations = if query.is_paged() {
+                    api.query(query.to_query()).await?
+                } else {
+                    api.query_all(query.to_query()).await?
+                };
+                output.print(&negotiations[..])
+            }
+            NegotiationCommand::Get { id } => output.print(&api.get(&id).await?),
+            NegotiationCommand::Agreement { id } => output.print(&api.get_agreement(&id).await?),
+            NegotiationCommand::Terminate { id, reason } => Ok(api.terminate(&id, &reason).await?),
+        }
+    }
+}
+
+impl Tabular for ContractNegotiation {
+    const HEADERS: &'static [&'static str] = &["ID", "TYPE", "STATE", "COUNTER PARTY", "AGREEMENT"];
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        vec![vec![
+            self.id().to_string(),
+            text(self.kind()),
+            text(self.state()),
+            self.counter_party_id().to_string(),
+            text(&self.contract_agreement_id()),
+        ]]
+    }
+}
@@ -0,0 +1,78 @@
+use std::path::PathBuf;
+
+use clap::Subcommand;
+use edc_connector_client::{
+    types::policy::{NewPolicyDefinition, PolicyDefinition},
+    EdcConnectorClient,
+};
+
+use crate::{
+    error::CliError,
+    output::{text, Created, Output, Tabular},
+    query::QueryArgs,
+};
+
+use super::read_json;
+
+#[derive(Debug, Subcommand)]
+pub enum PolicyCommand {
+    /// List the policy definitions matching the query.
+    List(QueryArgs),
+    /// Show a policy definition.
+    Get { id: String },
+    /// Create a policy definition from a JSON file.
+    Create {
+        #[arg(long, short)]
+        file: PathBuf,
+    },
+    /// Replace a policy definition with the one in a JSON file.
+    Update {
+        #[arg(long, short)]
+        file: PathBuf,
+    },
+    /// Delete a policy definition.
+    Delete { id: String },
+}
+
+impl PolicyCommand {
+    pub async fn run(self, client: &EdcConnectorClient, output: Output) -> Result<(), CliError> {
+        let api = client.policies();
+
+        match self {
+            PolicyCommand::List(query) => {
+                let policies = if query.is_paged() {
+                    api.query(query.to_query()).await?
+                } else {
+                    api.query_all(query.to_query()).await?
+                };
+                output.print(&policies[..])
+            }
+            PolicyCommand::Get { id } => output.print(&api.get(&id).await?),
+            PolicyCommand::Create { file } => {
+                let response = api
+                    .create(&read_json::<NewPolicyDefinition>(&file)?)
+                    .await?;
+                output.print(&Created { id: response.id() })
+            }
+            PolicyCommand::Update { file } => Ok(api.update(&read_json(&file)?).await?),
+            PolicyCommand::Delete { id } => Ok(api.delete(&id).await?),
+        }
+    }
+}
+
+impl Tabular for PolicyDefinition {
+    const HEADERS: &'static [&'static str] =
+        &["ID", "TYPE", "PERMISSIONS", "PROHIBITIONS", "OBLIGATIONS"];
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        let policy = self.policy();
+
+        vec![vec![
+            self.id().to_string(),
+            text(policy.kind()),
+            policy.permissions().len().to_string(),
+            policy.prohibitions().len().to_string(),
+            policy.obligations().len().to_string(),
+        ]]
+    }
+}
@@ -0,0 +1,67 @@
+use clap::Subcommand;
+use edc_connector_client::{types::transfer_process::TransferProcess, EdcConnectorClient};
+
+use crate::{
+    error::CliError,
+    output::{text, Output, Tabular},
+    query::QueryArgs,
+};
+
+#[derive(Debug, Subcommand)]
+pub enum TransferCommand {
+    /// List the transfer processes matching the query.
+    List(QueryArgs),
+    /// Show a transfer process.
+    Get { id: String },
+    /// Terminate a transfer process.
+    Terminate {
+        id: String,
+        #[arg(long)]
+        reason: String,
+    },
+    /// Suspend a transfer process.
+    Suspend {
+        id: String,
+        #[arg(long)]
+        reason: String,
+    },
+    /// Resume a suspended transfer process.
+    Resume { id: String },
+}
+
+impl TransferCommand {
+    pub async fn run(self, client: &EdcConnectorClient, output: Output) -> Result<(), CliError> {
+        let api = client.transfer_processes();
+
+        match self {
+            TransferCommand::List(query) => {
+                let transfers = if query.is_paged() {
+                    api.query(query.to_query()).await?
+                } else {
+                    api.query_all(query.to_query()).await?
+                };
+                output.print(&transfers[..])
+            }
+            TransferCommand::Get { id } => output.print(&api.get(&id).await?),
+            TransferCommand::Terminate { id, reason } => Ok(api.terminate(&id, &reason).await?),
+            TransferCommand::Suspend { id, reason } => Ok(api.suspend(&id, &reason).await?),
+            TransferCommand::Resume { id } => Ok(api.resume(&id).await?),
+        }
+    }
+}
+
+impl Tabular for TransferProcess {
+    const HEADERS: &'static [&'static str] =
+        &["ID", "TYPE", "STATE", "ASSET", "CONTRACT", "TRANSFER TYPE"];
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        vec![vec![
+            self.id().to_string(),
+            text(self.kind()),
+            text(self.state()),
+            self.asset_id().to_string(),
+            self.contract_id().to_string(),
+            self.transfer_type().to_string(),
+        ]]
+    }
+}
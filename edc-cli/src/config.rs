@@ -0,0 +1,217 @@
+use std::{collections::HashMap, env, fs, io::ErrorKind, path::PathBuf};
+
+use clap::Args;
+use edc_connector_client::{Auth, EdcConnectorClient};
+use serde::Deserialize;
+
+use crate::error::CliError;
+
+pub const DEFAULT_PROFILE: &str = "default";
+
+#[derive(Debug, Args)]
+pub struct ConnectionArgs {
+    /// Management API URL, e.g. http://localhost:29193/management.
+    #[arg(long, global = true, env = "EDC_MANAGEMENT_URL")]
+    management_url: Option<String>,
+    /// API key sent in the `X-Api-Key` header.
+    #[arg(long, global = true, env = "EDC_API_KEY", hide_env_values = true)]
+    api_key: Option<String>,
+    /// Profile read from the profile file [default: default].
+    #[arg(long, global = true, env = "EDC_PROFILE")]
+    profile: Option<String>,
+    /// Profile file [default: $XDG_CONFIG_HOME/edc/profiles.toml].
+    #[arg(long, global = true, env = "EDC_CONFIG")]
+    config: Option<PathBuf>,
+}
+
+impl ConnectionArgs {
+    pub fn client(&self) -> Result<EdcConnectorClient, CliError> {
+        let settings = self.settings()?;
+
+        let builder = EdcConnectorClient::builder().management_url(settings.management_url);
+
+        let builder = match settings.api_key {
+            Some(api_key) => builder.with_auth(Auth::api_token(api_key)),
+            None => builder,
+        };
+
+        Ok(builder.build()?)
+    }
+
+    fn settings(&self) -> Result<Settings, CliError> {
+        if let (Some(management_url), Some(api_key)) = (&self.management_url, &self.api_key) {
+            return Ok(Settings {
+                management_url: management_url.clone(),
+                api_key: Some(api_key.clone()),
+            });
+        }
+
+        let profiles = match self.config.clone().or_else(default_path) {
+            Some(path) => Profiles::load(path)?,
+            None => Profiles::default(),
+        };
+
+        let profile = match &self.profile {
+            Some(name) => Some(
+                profiles
+                    .get(name)
+                    .ok_or_else(|| CliError::UnknownProfile(name.clone()))?,
+            ),
+            None => profiles.get(DEFAULT_PROFILE),
+        };
+
+        Settings::resolve(self.management_url.clone(), self.api_key.clone(), profile)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Settings {
+    management_url: String,
+    api_key: Option<String>,
+}
+
+impl Settings {
+    /// Values given as flags or environment variables win over the profile.
+    ///
+    /// The API key of the profile is only sent to the management URL of the
+    /// same profile, another URL gets no API key unless one is given.
+    pub fn resolve(
+        management_url: Option<String>,
+        api_key: Option<String>,
+        profile: Option<&Profile>,
+    ) -> Result<Settings, CliError> {
+        let profile_url = profile.and_then(|p| p.management_url.as_deref());
+        let profile_key = profile.and_then(|p| p.api_key.clone());
+
+        let management_url = management_url
+            .or_else(|| profile_url.map(str::to_string))
+            .ok_or(CliError::MissingManagementUrl)?;
+
+        let api_key = api_key.or_else(|| {
+            profile_key.filter(|_| profile_url.is_some_and(|url| same_url(url, &management_url)))
+        });
+
+        Ok(Settings {
+            management_url,
+            api_key,
+        })
+    }
+}
+
+fn same_url(left: &str, right: &str) -> bool {
+    left.trim_end_matches('/') == right.trim_end_matches('/')
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Profile {
+    management_url: Option<String>,
+    api_key: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct Profiles(HashMap<String, Profile>);
+
+impl Profiles {
+    /// Loads the profiles, a missing file has no profiles.
+    pub fn load(path: PathBuf) -> Result<Profiles, CliError> {
+        match fs::read_to_string(&path) {
+            Ok(content) => {
+                toml::from_str(&content).map_err(|source| CliError::Profiles { path, source })
+            }
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(Profiles::default()),
+            Err(source) => Err(CliError::Read { path, source }),
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Profile> {
+        self.0.get(name)
+    }
+}
+
+fn default_path() -> Option<PathBuf> {
+    env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .map(|config| config.join("edc").join("profiles.toml"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Profiles, Settings};
+    use crate::error::CliError;
+
+    const PROFILES: &str = r#"
+        [default]
+        management_url = "http://localhost:29193/management"
+        api_key = "password"
+
+        [provider]
+        management_url = "http://provider:19193/management"
+    "#;
+
+    fn profiles() -> Profiles {
+        toml::from_str(PROFILES).unwrap()
+    }
+
+    #[test]
+    fn should_read_settings_from_profile() {
+        let profiles = profiles();
+
+        let settings = Settings::resolve(None, None, profiles.get("default")).unwrap();
+
+        assert_eq!(settings.management_url, "http://localhost:29193/management");
+        assert_eq!(settings.api_key.as_deref(), Some("password"));
+    }
+
+    #[test]
+    fn should_prefer_flags_over_profile() {
+        let profiles = profiles();
+
+        let settings = Settings::resolve(
+            Some("http://other:29193/management".to_string()),
+            Some("other-password".to_string()),
+            profiles.get("default"),
+        )
+        .unwrap();
+
+        assert_eq!(settings.management_url, "http://other:29193/management");
+        assert_eq!(settings.api_key.as_deref(), Some("other-password"));
+
+        let settings = Settings::resolve(None, None, profiles.get("provider")).unwrap();
+
+        assert_eq!(settings.api_key, None);
+    }
+
+    #[test]
+    fn should_not_send_the_profile_key_to_another_url() {
+        let profiles = profiles();
+
+        let settings = Settings::resolve(
+            Some("http://other:29193/management".to_string()),
+            None,
+            profiles.get("default"),
+        )
+        .unwrap();
+
+        assert_eq!(settings.management_url, "http://other:29193/management");
+        assert_eq!(settings.api_key, None);
+
+        let settings = Settings::resolve(
+            Some("http://localhost:29193/management/".to_string()),
+            None,
+            profiles.get("default"),
+        )
+        .unwrap();
+
+        assert_eq!(settings.api_key.as_deref(), Some("password"));
+    }
+
+    #[test]
+    fn should_require_management_url() {
+        let result = Settings::resolve(None, Some("password".to_string()), None);
+
+        assert!(matches!(result, Err(CliError::MissingManagementUrl)));
+    }
+}
@@ -0,0 +1,37 @@
+use std::path::PathBuf;
+
+use edc_connector_client::{BuilderError, Error};
+
+#[derive(Debug, thiserror::Error)]
+pub enum CliError {
+    #[error(transparent)]
+    Edc(#[from] Error),
+    #[error(transparent)]
+    Builder(#[from] BuilderError),
+    #[error("Failed to read {path}: {source}")]
+    Read {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("Invalid JSON in {path}: {source}")]
+    Json {
+        path: PathBuf,
+        #[source]
+        source: serde_json::Error,
+    },
+    #[error("Invalid profile file {path}: {source}")]
+    Profiles {
+        path: PathBuf,
+        #[source]
+        source: toml::de::Error,
+    },
+    #[error("Profile `{0}` not found")]
+    UnknownProfile(String),
+    #[error("Missing management URL, use --management-url, EDC_MANAGEMENT_URL or a profile file")]
+    MissingManagementUrl,
+    #[error("Failed to serialize the output: {0}")]
+    Serialize(#[from] serde_json::Error),
+    #[error("Failed to write the output: {0}")]
+    Write(#[from] std::io::Error),
+}
@@ -0,0 +1,67 @@
+//! Command-line tool for the EDC management API.
+//!
+//! The connection is configured with flags, environment variables or a
+//! profile file, in this order of precedence:
+//!
+//! ```toml
+//! [default]
+//! management_url = "http://localhost:29193/management"
+//! api_key = "password"
+//!
+//! [provider]
+//! management_url = "http://provider:19193/management"
+//! ```
+
+use std::process::ExitCode;
+
+use clap::Parser;
+
+use crate::{commands::Command, config::ConnectionArgs, error::CliError, output::Output};
+
+mod commands;
+mod config;
+mod error;
+mod output;
+mod query;
+
+#[derive(Debug, Parser)]
+#[command(name = "edc-cli", version, about = "Inspect and manage EDC connectors")]
+struct Cli {
+    #[command(flatten)]
+    connection: ConnectionArgs,
+    /// Output format.
+    #[arg(long, short, global = true, value_enum, default_value_t = Output::Table)]
+    output: Output,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match run(cli).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn run(cli: Cli) -> Result<(), CliError> {
+    let client = cli.connection.client()?;
+    cli.command.run(&client, cli.output).await
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::CommandFactory;
+
+    use super::Cli;
+
+    #[test]
+    fn should_define_a_valid_command() {
+        Cli::command().debug_assert();
+    }
+}
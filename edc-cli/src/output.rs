@@ -0,0 +1,150 @@
+use std::{
+    fmt::{self, Display},
+    io::{self, ErrorKind, Write},
+};
+
+use clap::ValueEnum;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::error::CliError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Output {
+    Table,
+    Json,
+}
+
+impl Output {
+    pub fn print<T>(self, value: &T) -> Result<(), CliError>
+    where
+        T: Serialize + Tabular + ?Sized,
+    {
+        let mut stdout = io::stdout().lock();
+
+        let result = match self {
+            Output::Table => write!(stdout, "{}", Table::of(value)),
+            Output::Json => writeln!(stdout, "{}", serde_json::to_string_pretty(value)?),
+        };
+
+        match result {
+            // The reader went away, e.g. piped into `head`.
+            Err(err) if err.kind() == ErrorKind::BrokenPipe => Ok(()),
+            result => Ok(result?),
+        }
+    }
+}
+
+/// Values printed as table rows.
+pub trait Tabular {
+    const HEADERS: &'static [&'static str];
+
+    fn rows(&self) -> Vec<Vec<String>>;
+}
+
+impl<T: Tabular> Tabular for [T] {
+    const HEADERS: &'static [&'static str] = T::HEADERS;
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        self.iter().flat_map(Tabular::rows).collect()
+    }
+}
+
+/// Id of a created resource.
+#[derive(Serialize)]
+pub struct Created<'a> {
+    #[serde(rename = "@id")]
+    pub id: &'a str,
+}
+
+impl Tabular for Created<'_> {
+    const HEADERS: &'static [&'static str] = &["ID"];
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        vec![vec![self.id.to_string()]]
+    }
+}
+
+/// Renders a value as table cell.
+pub fn text<T: Serialize + ?Sized>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(Value::Null) => String::new(),
+        Ok(Value::String(value)) => value,
+        Ok(Value::Array(values)) => values.iter().map(text).collect::<Vec<_>>().join(", "),
+        Ok(value) => value.to_string(),
+        Err(_) => String::new(),
+    }
+}
+
+pub struct Table {
+    headers: &'static [&'static str],
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn of<T: Tabular + ?Sized>(value: &T) -> Table {
+        Table {
+            headers: T::HEADERS,
+            rows: value.rows(),
+        }
+    }
+
+    fn widths(&self) -> Vec<usize> {
+        self.headers
+            .iter()
+            .enumerate()
+            .map(|(idx, header)| {
+                self.rows
+                    .iter()
+                    .filter_map(|row| row.get(idx))
+                    .map(|cell| cell.chars().count())
+                    .chain([header.chars().count()])
+                    .max()
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+}
+
+impl Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let widths = self.widths();
+        let headers = self.headers.iter().map(|header| header.to_string());
+
+        for row in [headers.collect::<Vec<_>>()].iter().chain(&self.rows) {
+            let line = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:width$}"))
+                .collect::<Vec<_>>()
+                .join("  ");
+            writeln!(f, "{}", line.trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Created, Table};
+
+    #[test]
+    fn should_align_columns() {
+        let created = [Created { id: "asset-1" }, Created { id: "a" }];
+
+        assert_eq!(Table::of(&created[..]).to_string(), "ID\nasset-1\na\n");
+
+        let table = Table {
+            headers: &["ID", "STATE"],
+            rows: vec![
+                vec!["negotiation-1".to_string(), "FINALIZED".to_string()],
+                vec!["n2".to_string(), String::new()],
+            ],
+        };
+
+        assert_eq!(
+            table.to_string(),
+            "ID             STATE\nnegotiation-1  FINALIZED\nn2\n"
+        );
+    }
+}
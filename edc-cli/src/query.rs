@@ -0,0 +1,279 @@
+use std::str::FromStr;
+
+use clap::Args;
+use edc_connector_client::types::query::{Query, SortOrder};
+
+const SYMBOLIC_OPERATORS: &[&str] = &["<=", ">=", "!=", "=", "<", ">"];
+const WORD_OPERATORS: &[&str] = &["like", "ilike", "in", "contains"];
+
+#[derive(Debug, Clone, Args)]
+pub struct QueryArgs {
+    /// Filter as `<left> <operator> <right>`, e.g. `id = asset-1` or
+    /// `id in a,b`. Repeat for more filters.
+    #[arg(long = "query", short = 'q', value_name = "FILTER")]
+    filters: Vec<Filter>,
+    /// Sort as `<field>` or `<field>:desc`.
+    #[arg(long)]
+    sort: Option<SortArg>,
+    /// Maximum number of results, all results are fetched if not set.
+    #[arg(long)]
+    limit: Option<u32>,
+    /// Index of the first result.
+    #[arg(long)]
+    offset: Option<u32>,
+}
+
+impl QueryArgs {
+    /// Whether a single page is requested instead of all the results.
+    pub fn is_paged(&self) -> bool {
+        self.limit.is_some() || self.offset.is_some()
+    }
+
+    pub fn to_query(&self) -> Query {
+        let builder = self
+            .filters
+            .iter()
+            .fold(Query::builder(), |builder, filter| match &filter.right {
+                Operand::Single(right) => {
+                    builder.filter(&filter.left, &filter.operator, right.as_str())
+                }
+                Operand::List(right) => {
+                    builder.filter(&filter.left, &filter.operator, right.clone())
+                }
+            });
+
+        let builder = match &self.sort {
+            Some(sort) => builder.sort(&sort.field, sort.order()),
+            None => builder,
+        };
+
+        let builder = match self.limit {
+            Some(limit) => builder.limit(limit),
+            None => builder,
+        };
+
+        match self.offset {
+            Some(offset) => builder.offset(offset),
+            None => builder,
+        }
+        .build()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Filter {
+    left: String,
+    operator: String,
+    right: Operand,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Operand {
+    Single(String),
+    List(Vec<String>),
+}
+
+impl FromStr for Filter {
+    type Err = String;
+
+    fn from_str(filter: &str) -> Result<Self, Self::Err> {
+        let (left, operator, right) = split_words(filter)
+            .or_else(|| split_symbolic(filter))
+            .ok_or_else(|| {
+                format!(
+                    "expected `<left> <operator> <right>` with one of {}",
+                    SYMBOLIC_OPERATORS
+                        .iter()
+                        .chain(WORD_OPERATORS)
+                        .copied()
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            })?;
+
+        let (left, right) = (left.trim(), right.trim());
+
+        if left.is_empty() || right.is_empty() {
+            return Err("both operands are required".to_string());
+        }
+
+        let right = if operator == "in" {
+            Operand::List(
+                right
+                    .split(',')
+                    .map(|value| unquote(value.trim()).to_string())
+                    .collect(),
+            )
+        } else {
+            Operand::Single(unquote(right).to_string())
+        };
+
+        Ok(Filter {
+            left: left.to_string(),
+            operator,
+            right,
+        })
+    }
+}
+
+/// Splits `left op right` where the operator is surrounded by whitespace.
+fn split_words(filter: &str) -> Option<(&str, String, &str)> {
+    let mut parts = filter.trim().splitn(3, char::is_whitespace);
+    let left = parts.next()?;
+    let operator = parts.next()?.to_lowercase();
+    let right = parts.next()?;
+
+    (SYMBOLIC_OPERATORS.contains(&operator.as_str()) || WORD_OPERATORS.contains(&operator.as_str()))
+        .then_some((left, operator, right))
+}
+
+/// Splits `left<op>right` at the first symbolic operator.
+fn split_symbolic(filter: &str) -> Option<(&str, String, &str)> {
+    filter.char_indices().find_map(|(idx, _)| {
+        SYMBOLIC_OPERATORS
+            .iter()
+            .find(|operator| filter[idx..].starts_with(*operator))
+            .map(|operator| {
+                (
+                    &filter[..idx],
+                    operator.to_string(),
+                    &filter[idx + operator.len()..],
+                )
+            })
+    })
+}
+
+fn unquote(value: &str) -> &str {
+    ['"', '\'']
+        .iter()
+        .find_map(|quote| {
+            value
+                .strip_prefix(*quote)
+                .and_then(|value| value.strip_suffix(*quote))
+        })
+        .unwrap_or(value)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SortArg {
+    field: String,
+    descending: bool,
+}
+
+impl SortArg {
+    fn order(&self) -> SortOrder {
+        if self.descending {
+            SortOrder::Desc
+        } else {
+            SortOrder::Asc
+        }
+    }
+}
+
+impl FromStr for SortArg {
+    type Err = String;
+
+    fn from_str(sort: &str) -> Result<Self, Self::Err> {
+        let (field, descending) = match sort.rsplit_once(':') {
+            Some((field, order)) if order.eq_ignore_ascii_case("asc") => (field, false),
+            Some((field, order)) if order.eq_ignore_ascii_case("desc") => (field, true),
+            _ => (sort, false),
+        };
+
+        if field.is_empty() {
+            return Err("the sort field is required".to_string());
+        }
+
+        Ok(SortArg {
+            field: field.to_string(),
+            descending,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+    use serde_json::json;
+
+    use super::{Filter, Operand, QueryArgs};
+
+    #[derive(Parser)]
+    struct TestCli {
+        #[command(flatten)]
+        query: QueryArgs,
+    }
+
+    fn filter(left: &str, operator: &str, right: Operand) -> Filter {
+        Filter {
+            left: left.to_string(),
+            operator: operator.to_string(),
+            right,
+        }
+    }
+
+    #[test]
+    fn should_parse_filters() {
+        assert_eq!(
+            "id = asset-1".parse::<Filter>().unwrap(),
+            filter("id", "=", Operand::Single("asset-1".to_string()))
+        );
+        assert_eq!(
+            "name>=b".parse::<Filter>().unwrap(),
+            filter("name", ">=", Operand::Single("b".to_string()))
+        );
+        assert_eq!(
+            "name LIKE '%user data%'".parse::<Filter>().unwrap(),
+            filter("name", "like", Operand::Single("%user data%".to_string()))
+        );
+        assert_eq!(
+            "id in a, b".parse::<Filter>().unwrap(),
+            filter(
+                "id",
+                "in",
+                Operand::List(vec!["a".to_string(), "b".to_string()])
+            )
+        );
+    }
+
+    #[test]
+    fn should_reject_invalid_filters() {
+        assert!("id".parse::<Filter>().is_err());
+        assert!("id =".parse::<Filter>().is_err());
+        assert!("id between a".parse::<Filter>().is_err());
+    }
+
+    #[test]
+    fn should_build_query() {
+        let cli = TestCli::parse_from([
+            "test",
+            "-q",
+            "id in a,b",
+            "--query",
+            "https://w3id.org/edc/v0.0.1/ns/name=report",
+            "--sort",
+            "createdAt:desc",
+            "--limit",
+            "10",
+        ]);
+
+        assert!(cli.query.is_paged());
+        assert_eq!(
+            serde_json::to_value(cli.query.to_query()).unwrap(),
+            json!({
+                "offset": 0,
+                "limit": 10,
+                "sortField": "createdAt",
+                "sortOrder": "DESC",
+                "filterExpression": [
+                    { "operandLeft": "id", "operator": "in", "operandRight": ["a", "b"] },
+                    {
+                        "operandLeft": "https://w3id.org/edc/v0.0.1/ns/name",
+                        "operator": "=",
+                        "operandRight": "report"
+                    }
+                ]
+            })
+        );
+    }
+}
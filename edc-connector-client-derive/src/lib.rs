@@ -0,0 +1,202 @@
+//! Derive macros for [`edc-connector-client`](https://docs.rs/edc-connector-client).
+//!
+//! Use them through the `derive` feature of `edc-connector-client` rather
+//! than depending on this crate directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_macro_input, Data, DeriveInput, Expr, Field, Fields, GenericArgument, LitStr,
+    PathArguments, Type,
+};
+
+/// Maps a struct with named fields to and from the `Properties` of an asset.
+///
+/// Container attributes:
+///
+/// - `#[edc(namespace = EXPR)]`: namespace prepended to the name of every
+///   field, e.g. `edc_connector_client::EDC_NAMESPACE` or
+///   `"http://purl.org/dc/terms/"`.
+///
+/// Field attributes:
+///
+/// - `#[edc(rename = "name")]`: property name, defaults to the field name.
+/// - `#[edc(namespace = EXPR)]`: namespace of the field, overriding the one
+///   of the container.
+/// - `#[edc(default)]`: use `Default::default()` when the property is missing.
+/// - `#[edc(skip)]`: never read nor written, always `Default::default()`.
+///
+/// `Option` fields are not required and are not written when `None`.
+#[proc_macro_derive(EdcProperties, attributes(edc))]
+pub fn derive_edc_properties(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[derive(Default)]
+struct Attributes {
+    namespace: Option<Expr>,
+    rename: Option<LitStr>,
+    default: bool,
+    skip: bool,
+}
+
+impl Attributes {
+    fn parse(attrs: &[syn::Attribute], container: bool) -> syn::Result<Attributes> {
+        let mut attributes = Attributes::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("edc")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("namespace") {
+                    attributes.namespace = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("rename") && !container {
+                    attributes.rename = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("default") && !container {
+                    attributes.default = true;
+                } else if meta.path.is_ident("skip") && !container {
+                    attributes.skip = true;
+                } else {
+                    return Err(meta.error("unsupported edc attribute"));
+                }
+                Ok(())
+            })?;
+        }
+
+        Ok(attributes)
+    }
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "EdcProperties can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "EdcProperties can only be derived for structs",
+            ))
+        }
+    };
+
+    let container = Attributes::parse(&input.attrs, true)?;
+
+    let mut reads = Vec::with_capacity(fields.len());
+    let mut writes = Vec::with_capacity(fields.len());
+
+    for field in fields {
+        let (read, write) = expand_field(field, &container)?;
+        reads.push(read);
+        writes.extend(write);
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let krate = quote!(::edc_connector_client::types::properties);
+
+    Ok(quote! {
+        impl #impl_generics #krate::EdcProperties for #name #ty_generics #where_clause {
+            fn from_properties(
+                properties: &#krate::Properties,
+            ) -> ::core::result::Result<Self, ::edc_connector_client::ConversionError> {
+                ::core::result::Result::Ok(Self {
+                    #(#reads,)*
+                })
+            }
+
+            fn into_properties(self) -> #krate::Properties {
+                let mut properties = #krate::Properties::default();
+                #(#writes)*
+                properties
+            }
+        }
+    })
+}
+
+fn expand_field(
+    field: &Field,
+    container: &Attributes,
+) -> syn::Result<(TokenStream2, Option<TokenStream2>)> {
+    let attributes = Attributes::parse(&field.attrs, false)?;
+    let ident = field.ident.as_ref().expect("named field");
+    let ty = &field.ty;
+
+    if attributes.skip {
+        return Ok((quote!(#ident: ::core::default::Default::default()), None));
+    }
+
+    let private = quote!(::edc_connector_client::types::properties::__private);
+    let field_name = ident.to_string();
+    let property = attributes
+        .rename
+        .map(|rename| rename.value())
+        .unwrap_or_else(|| field_name.trim_start_matches("r#").to_string());
+    let namespace = match attributes
+        .namespace
+        .as_ref()
+        .or(container.namespace.as_ref())
+    {
+        Some(namespace) => quote!(::core::option::Option::Some(#namespace)),
+        None => quote!(::core::option::Option::None),
+    };
+
+    let (read, write) = match option_inner(ty) {
+        Some(inner) => (
+            quote! {
+                #private::get::<#inner>(properties, #namespace, #property, #field_name)?
+            },
+            quote! {
+                #private::set_optional(&mut properties, #namespace, #property, self.#ident);
+            },
+        ),
+        None if attributes.default => (
+            quote! {
+                #private::get::<#ty>(properties, #namespace, #property, #field_name)?
+                    .unwrap_or_default()
+            },
+            quote! {
+                #private::set(&mut properties, #namespace, #property, self.#ident);
+            },
+        ),
+        None => (
+            quote! {
+                #private::required::<#ty>(properties, #namespace, #property, #field_name)?
+            },
+            quote! {
+                #private::set(&mut properties, #namespace, #property, self.#ident);
+            },
+        ),
+    };
+
+    Ok((quote!(#ident: #read), Some(write)))
+}
+
+/// Inner type of `Option<T>`, matched on the last path segment as `serde` does.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+
+    if segment.ident != "Option" {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            GenericArgument::Type(inner) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
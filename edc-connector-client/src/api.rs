@@ -1,9 +1,23 @@
 pub mod assets;
 pub mod catalog;
+pub mod consume;
 pub mod contract_agreement;
 pub mod contract_definitions;
 pub mod contract_negotiations;
 pub mod dataplanes;
 pub mod edrs;
+mod pagination;
 pub mod policies;
+pub mod public;
+pub mod secrets;
+mod services;
 pub mod transfer_process;
+pub(crate) mod upsert;
+mod wait;
+
+pub use services::{
+    AgreementStore, AssetStore, CatalogService, ContractDefinitionStore, DataPlaneRegistry,
+    EdrStore, NegotiationService, PolicyStore, SecretStore, TransferService,
+};
+pub use upsert::UpsertOutcome;
+pub use wait::{WaitOptions, WaitOptionsBuilder};
@@ -1,4 +1,10 @@
+use futures::{Stream, TryStreamExt};
+
 use crate::{
+    api::{
+        pagination::paginate,
+        upsert::{is_unchanged, UpsertOutcome, ASSET_MANAGED_VALUES},
+    },
     client::EdcConnectorClientInternal,
     types::{
         asset::{Asset, NewAsset},
@@ -6,7 +12,7 @@ use crate::{
         query::Query,
         response::IdResponse,
     },
-    EdcResult,
+    BuilderError, EdcResult,
 };
 
 pub struct AssetApi<'a>(&'a EdcConnectorClientInternal);
@@ -42,14 +48,47 @@ impl<'a> AssetApi<'a> {
             .await
     }
 
+    /// Creates the asset, or updates the existing one with the same id when
+    /// it differs from the desired state.
+    pub async fn upsert(&self, asset: &NewAsset) -> EdcResult<UpsertOutcome> {
+        let id = asset
+            .id()
+            .ok_or_else(|| BuilderError::missing_property("id"))?;
+
+        match self.get(id).await {
+            Ok(current) if is_unchanged(asset, &current, ASSET_MANAGED_VALUES) => {
+                Ok(UpsertOutcome::Unchanged)
+            }
+            Ok(_) => self
+                .update(&asset.to_asset(id))
+                .await
+                .map(|_| UpsertOutcome::Updated),
+            Err(err) if err.is_not_found() => {
+                self.create(asset).await.map(|_| UpsertOutcome::Created)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
     pub async fn query(&self, query: Query) -> EdcResult<Vec<Asset>> {
         let url = format!("{}/v3/assets/request", self.0.management_url);
         self.0
-            .post::<_, Vec<WithContext<Asset>>>(url, &WithContextRef::default_context(&query))
+            .query::<_, Vec<WithContext<Asset>>>(url, &WithContextRef::default_context(&query))
             .await
             .map(|results| results.into_iter().map(|ctx| ctx.inner).collect())
     }
 
+    pub fn query_stream(&self, query: Query) -> impl Stream<Item = EdcResult<Asset>> + 'a {
+        let client = self.0;
+        paginate(query, move |query| async move {
+            AssetApi(client).query(query).await
+        })
+    }
+
+    pub async fn query_all(&self, query: Query) -> EdcResult<Vec<Asset>> {
+        self.query_stream(query).try_collect().await
+    }
+
     pub async fn delete(&self, id: &str) -> EdcResult<()> {
         let url = format!("{}/v3/assets/{}", self.0.management_url, id);
         self.0.del(url).await
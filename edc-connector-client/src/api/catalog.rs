@@ -1,4 +1,13 @@
+use std::{
+    collections::HashSet,
+    future::Future,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use futures::{Stream, TryFutureExt, TryStreamExt};
+
 use crate::{
+    api::pagination::paginate_pages,
     client::EdcConnectorClientInternal,
     types::{
         catalog::{Catalog, CatalogRequest, Dataset, DatasetRequest},
@@ -17,16 +26,97 @@ impl<'a> CatalogApi<'a> {
     pub async fn request(&self, request: &CatalogRequest) -> EdcResult<Catalog> {
         let url = format!("{}/v3/catalog/request", self.0.management_url);
         self.0
-            .post::<_, WithContext<Catalog>>(url, &WithContextRef::default_context(request))
+            .query::<_, WithContext<Catalog>>(url, &WithContextRef::default_context(request))
             .await
             .map(|ctx| ctx.inner)
     }
 
+    /// Pages through the catalog by moving the offset of the query spec
+    /// forward, yielding the datasets of every page and of its sub-catalogs.
+    pub fn request_stream(
+        &self,
+        request: &CatalogRequest,
+    ) -> impl Stream<Item = EdcResult<Dataset>> + 'a {
+        let client = self.0;
+        let request = request.clone();
+        paginate_catalog(request, move |request| async move {
+            CatalogApi(client).request(&request).await
+        })
+    }
+
+    pub async fn request_all(&self, request: &CatalogRequest) -> EdcResult<Vec<Dataset>> {
+        self.request_stream(request).try_collect().await
+    }
+
     pub async fn dataset(&self, request: &DatasetRequest) -> EdcResult<Dataset> {
         let url = format!("{}/v3/catalog/dataset/request", self.0.management_url);
         self.0
-            .post::<_, WithContext<Dataset>>(url, &WithContextRef::default_context(request))
+            .query::<_, WithContext<Dataset>>(url, &WithContextRef::default_context(request))
             .await
             .map(|ctx| ctx.inner)
     }
 }
+
+/// Pages are counted in top-level entries, the datasets and the sub-catalogs
+/// of the catalog, and flattened into the datasets of all the catalogs.
+///
+/// Providers are not trusted to honor the offset: paging stops on a page
+/// repeating the entries of the previous one or without any new dataset.
+pub(crate) fn paginate_catalog<'a, F, Fut>(
+    request: CatalogRequest,
+    fetch: F,
+) -> impl Stream<Item = EdcResult<Dataset>> + 'a
+where
+    F: Fn(CatalogRequest) -> Fut + 'a,
+    Fut: Future<Output = EdcResult<Catalog>> + 'a,
+{
+    let query = request.query_spec().clone();
+    let seen = Arc::new(Mutex::new(SeenPages::default()));
+    paginate_pages(query, move |query| {
+        let seen = seen.clone();
+        fetch(request.with_query_spec(query)).map_ok(move |catalog| {
+            seen.lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .page(catalog)
+        })
+    })
+}
+
+#[derive(Default)]
+struct SeenPages {
+    dataset_ids: HashSet<String>,
+    previous_entries: Option<Vec<Option<String>>>,
+}
+
+impl SeenPages {
+    /// The number of entries and the datasets of a page, none when the page
+    /// is not making progress.
+    fn page(&mut self, catalog: Catalog) -> (usize, Vec<Dataset>) {
+        let entries = catalog
+            .datasets()
+            .iter()
+            .map(|dataset| Some(dataset.id().to_string()))
+            .chain(
+                catalog
+                    .catalogs()
+                    .iter()
+                    .map(|catalog| catalog.id().map(str::to_string)),
+            )
+            .collect::<Vec<_>>();
+        let repeated = self.previous_entries.as_ref() == Some(&entries);
+
+        let datasets = catalog.into_all_datasets();
+        let mut new_datasets = false;
+        for dataset in &datasets {
+            new_datasets |= self.dataset_ids.insert(dataset.id().to_string());
+        }
+
+        if repeated || (!datasets.is_empty() && !new_datasets) {
+            return (0, vec![]);
+        }
+
+        let len = entries.len();
+        self.previous_entries = Some(entries);
+        (len, datasets)
+    }
+}
@@ -0,0 +1,266 @@
+use std::future::Future;
+
+use futures::{
+    future::{self, BoxFuture, Shared},
+    FutureExt,
+};
+
+use crate::{
+    api::{
+        contract_negotiations::ContractNegotiationApi,
+        transfer_process::TransferProcessApi,
+        wait::{wait_until, WaitOptions},
+    },
+    client::EdcConnectorClientInternal,
+    types::{
+        callback_address::CallbackAddress,
+        catalog::Dataset,
+        contract_negotiation::{ContractNegotiation, ContractNegotiationState, ContractRequest},
+        data_address::DataAddress,
+        policy::Policy,
+        transfer_process::{TransferProcess, TransferProcessState, TransferRequest},
+    },
+    BuilderError, EdcResult, Error, WorkflowError,
+};
+
+const CANCELLATION_REASON: &str = "Cancelled by the consumer";
+
+/// Negotiates a contract for a dataset offer and optionally starts a transfer
+/// on the resulting agreement.
+///
+/// Created with [`EdcConnectorClient::consume`](crate::EdcConnectorClient::consume).
+pub struct Consume<'a> {
+    client: &'a EdcConnectorClientInternal,
+    dataset_id: String,
+    offer: Policy,
+    counter_party_id: Option<String>,
+    counter_party_address: Option<String>,
+    protocol: Option<String>,
+    callback_addresses: Vec<CallbackAddress>,
+    supervision: Supervision,
+}
+
+impl<'a> Consume<'a> {
+    pub(crate) fn new(
+        client: &'a EdcConnectorClientInternal,
+        dataset: &Dataset,
+        offer: &Policy,
+    ) -> Consume<'a> {
+        Consume {
+            client,
+            dataset_id: dataset.id().to_string(),
+            offer: offer.clone(),
+            counter_party_id: None,
+            counter_party_address: None,
+            protocol: None,
+            callback_addresses: vec![],
+            supervision: Supervision::default(),
+        }
+    }
+
+    pub fn counter_party_id(mut self, counter_party_id: &str) -> Self {
+        self.counter_party_id = Some(counter_party_id.to_string());
+        self
+    }
+
+    pub fn counter_party_address(mut self, counter_party_address: &str) -> Self {
+        self.counter_party_address = Some(counter_party_address.to_string());
+        self
+    }
+
+    pub fn protocol(mut self, protocol: &str) -> Self {
+        self.protocol = Some(protocol.to_string());
+        self
+    }
+
+    /// Registered on both the contract request and the transfer request.
+    pub fn callback_address(mut self, callback_address: CallbackAddress) -> Self {
+        self.callback_addresses.push(callback_address);
+        self
+    }
+
+    /// Polling strategy applied while waiting for the negotiation and,
+    /// separately, for the transfer.
+    pub fn wait_options(mut self, options: WaitOptions) -> Self {
+        self.supervision.options = options;
+        self
+    }
+
+    /// Cancels the workflow when `signal` completes.
+    ///
+    /// The negotiation or the transfer in progress is terminated on the
+    /// connector on a best-effort basis and [`WorkflowError::Cancelled`] is returned.
+    pub fn cancel_on(mut self, signal: impl Future<Output = ()> + Send + 'static) -> Self {
+        self.supervision.cancellation = Some(signal.boxed().shared());
+        self
+    }
+
+    /// Initiates the negotiation and waits for it to be finalized.
+    pub async fn negotiate(self) -> EdcResult<Negotiated<'a>> {
+        let counter_party_id = self
+            .counter_party_id
+            .ok_or_else(|| BuilderError::missing_property("counter_party_id"))?;
+        let counter_party_address = self
+            .counter_party_address
+            .ok_or_else(|| BuilderError::missing_property("counter_party_address"))?;
+
+        let mut request = ContractRequest::builder()
+            .counter_party_id(&counter_party_id)
+            .counter_party_address(&counter_party_address)
+            .policy(self.offer.to_offer(&counter_party_id, &self.dataset_id));
+
+        if let Some(protocol) = &self.protocol {
+            request = request.protocol(protocol);
+        }
+
+        for callback_address in &self.callback_addresses {
+            request = request.callback_address(callback_address.clone());
+        }
+
+        let api = ContractNegotiationApi::new(self.client);
+        let id = api.initiate(&request.build()?).await?.id().to_string();
+
+        let negotiation = self
+            .supervision
+            .supervise(
+                api.wait_for_state(
+                    &id,
+                    ContractNegotiationState::Finalized,
+                    &self.supervision.options,
+                ),
+                async {
+                    ContractNegotiationApi::new(self.client)
+                        .terminate(&id, CANCELLATION_REASON)
+                        .await
+                },
+            )
+            .await?;
+
+        let agreement_id = negotiation
+            .contract_agreement_id()
+            .cloned()
+            .ok_or_else(|| Error::Workflow(WorkflowError::MissingAgreement(id)))?;
+
+        Ok(Negotiated {
+            client: self.client,
+            counter_party_address,
+            protocol: self.protocol,
+            callback_addresses: self.callback_addresses,
+            supervision: self.supervision,
+            agreement_id,
+            negotiation,
+        })
+    }
+
+    /// Negotiates the contract and starts a transfer on the agreement.
+    pub async fn transfer(
+        self,
+        transfer_type: &str,
+        destination: Option<DataAddress>,
+    ) -> EdcResult<TransferProcess> {
+        self.negotiate()
+            .await?
+            .transfer(transfer_type, destination)
+            .await
+    }
+}
+
+/// A finalized contract negotiation, ready to start transfers.
+pub struct Negotiated<'a> {
+    client: &'a EdcConnectorClientInternal,
+    counter_party_address: String,
+    protocol: Option<String>,
+    callback_addresses: Vec<CallbackAddress>,
+    supervision: Supervision,
+    agreement_id: String,
+    negotiation: ContractNegotiation,
+}
+
+impl Negotiated<'_> {
+    pub fn negotiation(&self) -> &ContractNegotiation {
+        &self.negotiation
+    }
+
+    pub fn agreement_id(&self) -> &str {
+        &self.agreement_id
+    }
+
+    /// Initiates a transfer on the agreement and waits for it to be started.
+    ///
+    /// Transfers that complete before being observed as started are returned as well.
+    pub async fn transfer(
+        &self,
+        transfer_type: &str,
+        destination: Option<DataAddress>,
+    ) -> EdcResult<TransferProcess> {
+        let mut request = TransferRequest::builder()
+            .counter_party_address(&self.counter_party_address)
+            .contract_id(&self.agreement_id)
+            .transfer_type(transfer_type);
+
+        if let Some(protocol) = &self.protocol {
+            request = request.protocol(protocol);
+        }
+
+        if let Some(destination) = destination {
+            request = request.destination(destination);
+        }
+
+        for callback_address in &self.callback_addresses {
+            request = request.callback_address(callback_address.clone());
+        }
+
+        let api = TransferProcessApi::new(self.client);
+        let id = api.initiate(&request.build()?).await?.id().to_string();
+
+        self.supervision
+            .supervise(
+                wait_until(
+                    format!("transfer process {}", id),
+                    api.watch(&id, &self.supervision.options),
+                    &self.supervision.options,
+                    |state| {
+                        matches!(
+                            state,
+                            TransferProcessState::Started | TransferProcessState::Completed
+                        )
+                    },
+                ),
+                async {
+                    TransferProcessApi::new(self.client)
+                        .terminate(&id, CANCELLATION_REASON)
+                        .await
+                },
+            )
+            .await
+    }
+}
+
+#[derive(Clone, Default)]
+struct Supervision {
+    options: WaitOptions,
+    cancellation: Option<Shared<BoxFuture<'static, ()>>>,
+}
+
+impl Supervision {
+    async fn supervise<T>(
+        &self,
+        wait: impl Future<Output = EdcResult<T>>,
+        terminate: impl Future<Output = EdcResult<()>>,
+    ) -> EdcResult<T> {
+        let cancelled = async {
+            match &self.cancellation {
+                Some(cancellation) => cancellation.clone().await,
+                None => future::pending().await,
+            }
+        };
+
+        tokio::select! {
+            result = wait => result,
+            _ = cancelled => {
+                let _ = terminate.await;
+                Err(Error::Workflow(WorkflowError::Cancelled))
+            }
+        }
+    }
+}
@@ -1,8 +1,12 @@
+use futures::{Stream, TryStreamExt};
+
 use crate::{
+    api::pagination::paginate,
     client::EdcConnectorClientInternal,
     types::{
         context::{WithContext, WithContextRef},
         contract_agreement::ContractAgreement,
+        contract_negotiation::ContractNegotiation,
         query::Query,
     },
     EdcResult,
@@ -23,14 +27,39 @@ impl<'a> ContractAgreementApi<'a> {
             .map(|ctx| ctx.inner)
     }
 
+    pub async fn get_negotiation(&self, id: &str) -> EdcResult<ContractNegotiation> {
+        let url = format!(
+            "{}/v3/contractagreements/{}/negotiation",
+            self.0.management_url, id
+        );
+        self.0
+            .get::<WithContext<ContractNegotiation>>(url)
+            .await
+            .map(|ctx| ctx.inner)
+    }
+
     pub async fn query(&self, query: Query) -> EdcResult<Vec<ContractAgreement>> {
         let url = format!("{}/v3/contractagreements/request", self.0.management_url);
         self.0
-            .post::<_, Vec<WithContext<ContractAgreement>>>(
+            .query::<_, Vec<WithContext<ContractAgreement>>>(
                 url,
                 &WithContextRef::default_context(&query),
             )
             .await
             .map(|results| results.into_iter().map(|ctx| ctx.inner).collect())
     }
+
+    pub fn query_stream(
+        &self,
+        query: Query,
+    ) -> impl Stream<Item = EdcResult<ContractAgreement>> + 'a {
+        let client = self.0;
+        paginate(query, move |query| async move {
+            ContractAgreementApi(client).query(query).await
+        })
+    }
+
+    pub async fn query_all(&self, query: Query) -> EdcResult<Vec<ContractAgreement>> {
+        self.query_stream(query).try_collect().await
+    }
 }
@@ -1,4 +1,10 @@
+use futures::{Stream, TryStreamExt};
+
 use crate::{
+    api::{
+        pagination::paginate,
+        upsert::{is_unchanged, UpsertOutcome},
+    },
     client::EdcConnectorClientInternal,
     types::{
         context::{WithContext, WithContextRef},
@@ -6,7 +12,7 @@ use crate::{
         query::Query,
         response::IdResponse,
     },
-    EdcResult,
+    BuilderError, EdcResult,
 };
 
 pub struct ContractDefinitionApi<'a>(&'a EdcConnectorClientInternal);
@@ -45,10 +51,36 @@ impl<'a> ContractDefinitionApi<'a> {
             .await
     }
 
+    /// Creates the contract definition, or updates the existing one with the
+    /// same id when it differs from the desired state.
+    pub async fn upsert(
+        &self,
+        contract_definition: &NewContractDefinition,
+    ) -> EdcResult<UpsertOutcome> {
+        let id = contract_definition
+            .id()
+            .ok_or_else(|| BuilderError::missing_property("id"))?;
+
+        match self.get(id).await {
+            Ok(current) if is_unchanged(contract_definition, &current, &[]) => {
+                Ok(UpsertOutcome::Unchanged)
+            }
+            Ok(_) => self
+                .update(&contract_definition.to_contract_definition(id))
+                .await
+                .map(|_| UpsertOutcome::Updated),
+            Err(err) if err.is_not_found() => self
+                .create(contract_definition)
+                .await
+                .map(|_| UpsertOutcome::Created),
+            Err(err) => Err(err),
+        }
+    }
+
     pub async fn query(&self, query: Query) -> EdcResult<Vec<ContractDefinition>> {
         let url = format!("{}/v3/contractdefinitions/request", self.0.management_url);
         self.0
-            .post::<_, Vec<WithContext<ContractDefinition>>>(
+            .query::<_, Vec<WithContext<ContractDefinition>>>(
                 url,
                 &WithContextRef::default_context(&query),
             )
@@ -56,6 +88,20 @@ impl<'a> ContractDefinitionApi<'a> {
             .map(|results| results.into_iter().map(|ctx| ctx.inner).collect())
     }
 
+    pub fn query_stream(
+        &self,
+        query: Query,
+    ) -> impl Stream<Item = EdcResult<ContractDefinition>> + 'a {
+        let client = self.0;
+        paginate(query, move |query| async move {
+            ContractDefinitionApi(client).query(query).await
+        })
+    }
+
+    pub async fn query_all(&self, query: Query) -> EdcResult<Vec<ContractDefinition>> {
+        self.query_stream(query).try_collect().await
+    }
+
     pub async fn delete(&self, id: &str) -> EdcResult<()> {
         let url = format!("{}/v3/contractdefinitions/{}", self.0.management_url, id);
         self.0.del(url).await
@@ -1,7 +1,14 @@
+use futures::{Stream, TryStreamExt};
+
 use crate::{
+    api::{
+        pagination::paginate,
+        wait::{wait_until, watch, WaitOptions},
+    },
     client::EdcConnectorClientInternal,
     types::{
         context::{WithContext, WithContextRef},
+        contract_agreement::ContractAgreement,
         contract_negotiation::{
             ContractNegotiation, ContractNegotiationState, ContractRequest, NegotiationState,
             TerminateNegotiation,
@@ -41,6 +48,17 @@ impl<'a> ContractNegotiationApi<'a> {
             .map(|ctx| ctx.inner)
     }
 
+    pub async fn get_agreement(&self, id: &str) -> EdcResult<ContractAgreement> {
+        let url = format!(
+            "{}/v3/contractnegotiations/{}/agreement",
+            self.0.management_url, id
+        );
+        self.0
+            .get::<WithContext<ContractAgreement>>(url)
+            .await
+            .map(|ctx| ctx.inner)
+    }
+
     pub async fn get_state(&self, id: &str) -> EdcResult<ContractNegotiationState> {
         let url = format!("{}/v3/contractnegotiations/{}", self.0.management_url, id);
         self.0
@@ -49,6 +67,37 @@ impl<'a> ContractNegotiationApi<'a> {
             .map(|ctx| ctx.inner.state().clone())
     }
 
+    pub async fn wait_for_state(
+        &self,
+        id: &str,
+        target: ContractNegotiationState,
+        options: &WaitOptions,
+    ) -> EdcResult<ContractNegotiation> {
+        wait_until(
+            format!("contract negotiation {}", id),
+            self.watch(id, options),
+            options,
+            |state| state == &target,
+        )
+        .await
+    }
+
+    pub fn watch(
+        &self,
+        id: &str,
+        options: &WaitOptions,
+    ) -> impl Stream<Item = EdcResult<ContractNegotiation>> + 'a {
+        let client = self.0;
+        let id = id.to_string();
+        watch(
+            move || {
+                let id = id.clone();
+                async move { ContractNegotiationApi(client).get(&id).await }
+            },
+            options,
+        )
+    }
+
     pub async fn terminate(&self, id: &str, reason: &str) -> EdcResult<()> {
         let url = format!(
             "{}/v3/contractnegotiations/{}/terminate",
@@ -68,11 +117,25 @@ impl<'a> ContractNegotiationApi<'a> {
     pub async fn query(&self, query: Query) -> EdcResult<Vec<ContractNegotiation>> {
         let url = format!("{}/v3/contractnegotiations/request", self.0.management_url);
         self.0
-            .post::<_, Vec<WithContext<ContractNegotiation>>>(
+            .query::<_, Vec<WithContext<ContractNegotiation>>>(
                 url,
                 &WithContextRef::default_context(&query),
             )
             .await
             .map(|results| results.into_iter().map(|ctx| ctx.inner).collect())
     }
+
+    pub fn query_stream(
+        &self,
+        query: Query,
+    ) -> impl Stream<Item = EdcResult<ContractNegotiation>> + 'a {
+        let client = self.0;
+        paginate(query, move |query| async move {
+            ContractNegotiationApi(client).query(query).await
+        })
+    }
+
+    pub async fn query_all(&self, query: Query) -> EdcResult<Vec<ContractNegotiation>> {
+        self.query_stream(query).try_collect().await
+    }
 }
@@ -1,6 +1,12 @@
+use std::time::Duration;
+
 use reqwest::StatusCode;
 
+use futures::{Stream, TryStreamExt};
+use tokio::{sync::Mutex, time::Instant};
+
 use crate::{
+    api::{pagination::paginate, public::PublicApi},
     client::EdcConnectorClientInternal,
     types::{
         context::{WithContext, WithContextRef},
@@ -8,7 +14,7 @@ use crate::{
         edr::EndpointDataReferenceEntry,
         query::Query,
     },
-    EdcResult,
+    ConversionError, EdcResult,
 };
 
 pub struct EdrApi<'a>(&'a EdcConnectorClientInternal);
@@ -44,10 +50,44 @@ impl<'a> EdrApi<'a> {
             .map(|ctx| ctx.inner)
     }
 
+    /// With `auto_refresh` the connector refreshes an expired token before returning it.
+    pub async fn get_data_address_with_auto_refresh(
+        &self,
+        id: &str,
+        auto_refresh: bool,
+    ) -> EdcResult<DataAddress> {
+        let url = format!(
+            "{}/v3/edrs/{}/dataaddress?auto_refresh={}",
+            self.0.management_url, id, auto_refresh
+        );
+        self.0
+            .get::<WithContext<DataAddress>>(url)
+            .await
+            .map(|ctx| ctx.inner)
+    }
+
+    pub async fn refresh(&self, id: &str) -> EdcResult<DataAddress> {
+        let url = format!("{}/v3/edrs/{}/refresh", self.0.management_url, id);
+        self.0
+            .post_empty::<WithContext<DataAddress>>(url)
+            .await
+            .map(|ctx| ctx.inner)
+    }
+
+    /// A handle caching the EDR of the transfer process `id`.
+    pub fn handle(&self, id: &str) -> EdrHandle<'a> {
+        EdrHandle {
+            client: self.0,
+            id: id.to_string(),
+            refresh_skew: Duration::from_secs(30),
+            cached: Mutex::new(None),
+        }
+    }
+
     pub async fn query(&self, query: Query) -> EdcResult<Vec<EndpointDataReferenceEntry>> {
         let url = format!("{}/v3/edrs/request", self.0.management_url);
         self.0
-            .post::<_, Vec<WithContext<EndpointDataReferenceEntry>>>(
+            .query::<_, Vec<WithContext<EndpointDataReferenceEntry>>>(
                 url,
                 &WithContextRef::default_context(&query),
             )
@@ -55,8 +95,105 @@ impl<'a> EdrApi<'a> {
             .map(|results| results.into_iter().map(|ctx| ctx.inner).collect())
     }
 
+    pub fn query_stream(
+        &self,
+        query: Query,
+    ) -> impl Stream<Item = EdcResult<EndpointDataReferenceEntry>> + 'a {
+        let client = self.0;
+        paginate(query, move |query| async move {
+            EdrApi(client).query(query).await
+        })
+    }
+
+    pub async fn query_all(&self, query: Query) -> EdcResult<Vec<EndpointDataReferenceEntry>> {
+        self.query_stream(query).try_collect().await
+    }
+
     pub async fn delete(&self, id: &str) -> EdcResult<()> {
         let url = format!("{}/v3/edrs/{}", self.0.management_url, id);
         self.0.del(url).await
     }
 }
+
+/// Cached EDR of a transfer process.
+///
+/// The data address is fetched with auto refresh on first use. Once its
+/// `expiresIn` is about to elapse, the token is refreshed on the connector, so
+/// that long running consumers can keep calling the data plane.
+pub struct EdrHandle<'a> {
+    client: &'a EdcConnectorClientInternal,
+    id: String,
+    refresh_skew: Duration,
+    cached: Mutex<Option<CachedEdr>>,
+}
+
+struct CachedEdr {
+    data_address: DataAddress,
+    expires_at: Option<Instant>,
+}
+
+impl CachedEdr {
+    /// `issued_at` is taken before requesting the data address, as `expiresIn`
+    /// counts from when the token was issued.
+    fn new(data_address: DataAddress, issued_at: Instant) -> Result<CachedEdr, ConversionError> {
+        let expires_at = data_address
+            .property::<u64>("expiresIn")?
+            .map(|expires_in| issued_at + Duration::from_secs(expires_in));
+
+        Ok(CachedEdr {
+            data_address,
+            expires_at,
+        })
+    }
+
+    fn is_fresh(&self, refresh_skew: Duration) -> bool {
+        self.expires_at
+            .map(|expires_at| Instant::now() + refresh_skew < expires_at)
+            .unwrap_or(true)
+    }
+}
+
+impl<'a> EdrHandle<'a> {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// How long before its expiry the EDR is refreshed. Defaults to 30 seconds.
+    pub fn refresh_skew(mut self, refresh_skew: Duration) -> Self {
+        self.refresh_skew = refresh_skew;
+        self
+    }
+
+    /// The cached data address, fetched or refreshed when needed. Fails with
+    /// [`Error::Conversion`](crate::Error::Conversion) when its `expiresIn` is
+    /// not a number of seconds, instead of caching it forever.
+    pub async fn data_address(&self) -> EdcResult<DataAddress> {
+        let mut cached = self.cached.lock().await;
+
+        let requested_at = Instant::now();
+        let data_address = match cached.as_ref() {
+            Some(edr) if edr.is_fresh(self.refresh_skew) => return Ok(edr.data_address.clone()),
+            // The connector only refreshes tokens that already expired
+            Some(_) => EdrApi(self.client).refresh(&self.id).await?,
+            None => {
+                EdrApi(self.client)
+                    .get_data_address_with_auto_refresh(&self.id, true)
+                    .await?
+            }
+        };
+        *cached = Some(CachedEdr::new(data_address.clone(), requested_at)?);
+
+        Ok(data_address)
+    }
+
+    /// Public API of the data plane, authenticated with a fresh EDR.
+    pub async fn public_api(&self) -> EdcResult<PublicApi<'a>> {
+        PublicApi::new(self.client, &self.data_address().await?)
+    }
+
+    /// Forces the next call to fetch the EDR again, e.g. after the data
+    /// plane rejected the token.
+    pub async fn invalidate(&self) {
+        self.cached.lock().await.take();
+    }
+}
@@ -0,0 +1,58 @@
+use std::future::Future;
+
+use futures::{stream, Stream, TryFutureExt, TryStreamExt};
+
+use crate::{types::query::Query, EdcResult, Error};
+
+/// Walks the pages of a query by moving its offset forward by `limit` until a
+/// page shorter than `limit` is returned.
+pub(crate) fn paginate<'a, T, F, Fut>(
+    query: Query,
+    fetch: F,
+) -> impl Stream<Item = EdcResult<T>> + 'a
+where
+    T: 'a,
+    F: Fn(Query) -> Fut + 'a,
+    Fut: Future<Output = EdcResult<Vec<T>>> + 'a,
+{
+    paginate_pages(query, move |query| {
+        fetch(query).map_ok(|page| (page.len(), page))
+    })
+}
+
+/// Like [`paginate`], for pages whose length, as counted by the server, is
+/// not the number of items they are flattened into.
+pub(crate) fn paginate_pages<'a, T, F, Fut>(
+    query: Query,
+    fetch: F,
+) -> impl Stream<Item = EdcResult<T>> + 'a
+where
+    T: 'a,
+    F: Fn(Query) -> Fut + 'a,
+    Fut: Future<Output = EdcResult<(usize, Vec<T>)>> + 'a,
+{
+    stream::try_unfold((fetch, Some(query)), |(fetch, query)| async move {
+        let Some(query) = query else {
+            return Ok::<_, Error>(None);
+        };
+
+        let (len, page) = fetch(query.clone()).await?;
+
+        let next = if query.limit() == 0 || len < query.limit() as usize {
+            None
+        } else {
+            Some(
+                query
+                    .to_builder()
+                    .offset(query.offset() + query.limit())
+                    .build(),
+            )
+        };
+
+        Ok(Some((
+            stream::iter(page.into_iter().map(Ok)),
+            (fetch, next),
+        )))
+    })
+    .try_flatten()
+}
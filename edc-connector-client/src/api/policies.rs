@@ -1,12 +1,21 @@
+use futures::{Stream, TryStreamExt};
+
 use crate::{
+    api::{
+        pagination::paginate,
+        upsert::{is_unchanged, UpsertOutcome, POLICY_DEFINITION_MANAGED_VALUES},
+    },
     client::EdcConnectorClientInternal,
     types::{
         context::{WithContext, WithContextRef},
-        policy::{NewPolicyDefinition, PolicyDefinition},
+        policy::{
+            NewPolicyDefinition, PolicyDefinition, PolicyEvaluationPlan,
+            PolicyEvaluationPlanRequest, PolicyValidationResult,
+        },
         query::Query,
         response::IdResponse,
     },
-    EdcResult,
+    BuilderError, EdcResult,
 };
 
 pub struct PolicyApi<'a>(&'a EdcConnectorClientInternal);
@@ -49,10 +58,42 @@ impl<'a> PolicyApi<'a> {
             .await
     }
 
+    /// Creates the policy definition, or updates the existing one with the
+    /// same id when it differs from the desired state.
+    pub async fn upsert(
+        &self,
+        policy_definition: &NewPolicyDefinition,
+    ) -> EdcResult<UpsertOutcome> {
+        let id = policy_definition
+            .id()
+            .ok_or_else(|| BuilderError::missing_property("id"))?;
+
+        match self.get(id).await {
+            Ok(current)
+                if is_unchanged(
+                    policy_definition,
+                    &current,
+                    POLICY_DEFINITION_MANAGED_VALUES,
+                ) =>
+            {
+                Ok(UpsertOutcome::Unchanged)
+            }
+            Ok(_) => self
+                .update(&policy_definition.to_policy_definition(id))
+                .await
+                .map(|_| UpsertOutcome::Updated),
+            Err(err) if err.is_not_found() => self
+                .create(policy_definition)
+                .await
+                .map(|_| UpsertOutcome::Created),
+            Err(err) => Err(err),
+        }
+    }
+
     pub async fn query(&self, query: Query) -> EdcResult<Vec<PolicyDefinition>> {
         let url = format!("{}/v3/policydefinitions/request", self.0.management_url);
         self.0
-            .post::<_, Vec<WithContext<PolicyDefinition>>>(
+            .query::<_, Vec<WithContext<PolicyDefinition>>>(
                 url,
                 &WithContextRef::default_context(&query),
             )
@@ -60,6 +101,50 @@ impl<'a> PolicyApi<'a> {
             .map(|results| results.into_iter().map(|ctx| ctx.inner).collect())
     }
 
+    pub fn query_stream(
+        &self,
+        query: Query,
+    ) -> impl Stream<Item = EdcResult<PolicyDefinition>> + 'a {
+        let client = self.0;
+        paginate(query, move |query| async move {
+            PolicyApi(client).query(query).await
+        })
+    }
+
+    pub async fn query_all(&self, query: Query) -> EdcResult<Vec<PolicyDefinition>> {
+        self.query_stream(query).try_collect().await
+    }
+
+    pub async fn validate(&self, id: &str) -> EdcResult<PolicyValidationResult> {
+        let url = format!(
+            "{}/v3/policydefinitions/{}/validate",
+            self.0.management_url, id
+        );
+        self.0
+            .query_empty::<WithContext<PolicyValidationResult>>(url)
+            .await
+            .map(|ctx| ctx.inner)
+    }
+
+    pub async fn evaluation_plan(&self, id: &str, scope: &str) -> EdcResult<PolicyEvaluationPlan> {
+        let url = format!(
+            "{}/v3/policydefinitions/{}/evaluationplan",
+            self.0.management_url, id
+        );
+
+        let request = PolicyEvaluationPlanRequest {
+            kind: "PolicyEvaluationPlanRequest",
+            policy_scope: scope.to_string(),
+        };
+        self.0
+            .query::<_, WithContext<PolicyEvaluationPlan>>(
+                url,
+                &WithContextRef::default_context(&request),
+            )
+            .await
+            .map(|ctx| ctx.inner)
+    }
+
     pub async fn delete(&self, id: &str) -> EdcResult<()> {
         let url = format!("{}/v3/policydefinitions/{}", self.0.management_url, id);
         self.0.del(url).await
@@ -0,0 +1,107 @@
+use reqwest::{Method, RequestBuilder, Response};
+use serde::Serialize;
+
+use crate::{
+    client::EdcConnectorClientInternal, error::DataPlaneError, types::data_address::DataAddress,
+    BuilderError, EdcResult, Error,
+};
+
+/// Client for the public API of a data plane, authenticated with the
+/// `endpoint` and `authorization` of an EDR data address.
+///
+/// Requests are sent with the HTTP client of the connector client, without
+/// its management authentication and interceptors.
+pub struct PublicApi<'a> {
+    client: &'a EdcConnectorClientInternal,
+    endpoint: String,
+    authorization: Option<String>,
+}
+
+impl<'a> PublicApi<'a> {
+    pub(crate) fn new(
+        client: &'a EdcConnectorClientInternal,
+        data_address: &DataAddress,
+    ) -> EdcResult<PublicApi<'a>> {
+        let endpoint = data_address
+            .property::<String>("endpoint")?
+            .ok_or_else(|| BuilderError::missing_property("endpoint"))?;
+
+        let authorization = data_address.property::<String>("authorization")?;
+
+        Ok(PublicApi {
+            client,
+            endpoint,
+            authorization,
+        })
+    }
+
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    pub fn get(&self, path: &str) -> PublicRequest {
+        self.request(Method::GET, path)
+    }
+
+    pub fn post(&self, path: &str) -> PublicRequest {
+        self.request(Method::POST, path)
+    }
+
+    /// A request to `path`, relative to the endpoint of the data address.
+    pub fn request(&self, method: Method, path: &str) -> PublicRequest {
+        let url = if path.trim_start_matches('/').is_empty() {
+            self.endpoint.clone()
+        } else {
+            format!(
+                "{}/{}",
+                self.endpoint.trim_end_matches('/'),
+                path.trim_start_matches('/')
+            )
+        };
+
+        let mut builder = self.client.client.request(method, url);
+
+        if let Some(authorization) = &self.authorization {
+            builder = builder.header(reqwest::header::AUTHORIZATION, authorization);
+        }
+
+        PublicRequest(builder)
+    }
+}
+
+pub struct PublicRequest(RequestBuilder);
+
+impl PublicRequest {
+    pub fn query<T: Serialize + ?Sized>(self, query: &T) -> Self {
+        PublicRequest(self.0.query(query))
+    }
+
+    pub fn header(self, name: &str, value: &str) -> Self {
+        PublicRequest(self.0.header(name, value))
+    }
+
+    /// Accepts any body, including streams wrapped with [`reqwest::Body::wrap_stream`].
+    pub fn body(self, body: impl Into<reqwest::Body>) -> Self {
+        PublicRequest(self.0.body(body))
+    }
+
+    pub fn json<T: Serialize + ?Sized>(self, json: &T) -> Self {
+        PublicRequest(self.0.json(json))
+    }
+
+    /// Sends the request, failing on non-success statuses.
+    ///
+    /// The body of the returned response can be streamed with
+    /// [`Response::bytes_stream`].
+    pub async fn send(self) -> EdcResult<Response> {
+        let response = self.0.send().await?;
+
+        if response.status().is_success() {
+            Ok(response)
+        } else {
+            let status_code = response.status();
+            let body = response.text().await?;
+            Err(Error::DataPlane(DataPlaneError { status_code, body }))
+        }
+    }
+}
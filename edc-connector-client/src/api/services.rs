@@ -0,0 +1,537 @@
+//! The management API groups as traits, so application code can depend on
+//! them and swap the HTTP client for test doubles or for layers like caches.
+//!
+//! Method names carry the resource, since [`EdcConnectorClient`] implements
+//! all of them.
+
+use futures::TryStreamExt;
+
+use crate::{
+    api::{
+        catalog::paginate_catalog,
+        pagination::paginate,
+        upsert::UpsertOutcome,
+        wait::{wait_until, watch, WaitOptions},
+    },
+    types::{
+        asset::{Asset, NewAsset},
+        catalog::{Catalog, CatalogRequest, Dataset, DatasetRequest},
+        contract_agreement::ContractAgreement,
+        contract_definition::{ContractDefinition, NewContractDefinition},
+        contract_negotiation::{ContractNegotiation, ContractNegotiationState, ContractRequest},
+        data_address::DataAddress,
+        dataplane::DataPlaneInstance,
+        edr::EndpointDataReferenceEntry,
+        policy::{
+            NewPolicyDefinition, PolicyDefinition, PolicyEvaluationPlan, PolicyValidationResult,
+        },
+        query::Query,
+        response::IdResponse,
+        secret::{NewSecret, Secret},
+        transfer_process::{TransferProcess, TransferProcessState, TransferRequest},
+    },
+    EdcConnectorClient, EdcResult,
+};
+
+#[async_trait::async_trait]
+pub trait AssetStore: Send + Sync {
+    async fn create_asset(&self, asset: &NewAsset) -> EdcResult<IdResponse<String>>;
+
+    async fn get_asset(&self, id: &str) -> EdcResult<Asset>;
+
+    async fn update_asset(&self, asset: &Asset) -> EdcResult<()>;
+
+    async fn upsert_asset(&self, asset: &NewAsset) -> EdcResult<UpsertOutcome>;
+
+    async fn query_assets(&self, query: Query) -> EdcResult<Vec<Asset>>;
+
+    /// Fetches every page of the query.
+    async fn query_all_assets(&self, query: Query) -> EdcResult<Vec<Asset>> {
+        paginate(query, |query| self.query_assets(query))
+            .try_collect()
+            .await
+    }
+
+    async fn delete_asset(&self, id: &str) -> EdcResult<()>;
+}
+
+#[async_trait::async_trait]
+pub trait PolicyStore: Send + Sync {
+    async fn create_policy(
+        &self,
+        policy_definition: &NewPolicyDefinition,
+    ) -> EdcResult<IdResponse<String>>;
+
+    async fn get_policy(&self, id: &str) -> EdcResult<PolicyDefinition>;
+
+    async fn update_policy(&self, policy_definition: &PolicyDefinition) -> EdcResult<()>;
+
+    async fn upsert_policy(
+        &self,
+        policy_definition: &NewPolicyDefinition,
+    ) -> EdcResult<UpsertOutcome>;
+
+    async fn query_policies(&self, query: Query) -> EdcResult<Vec<PolicyDefinition>>;
+
+    /// Fetches every page of the query.
+    async fn query_all_policies(&self, query: Query) -> EdcResult<Vec<PolicyDefinition>> {
+        paginate(query, |query| self.query_policies(query))
+            .try_collect()
+            .await
+    }
+
+    async fn validate_policy(&self, id: &str) -> EdcResult<PolicyValidationResult>;
+
+    async fn policy_evaluation_plan(
+        &self,
+        id: &str,
+        scope: &str,
+    ) -> EdcResult<PolicyEvaluationPlan>;
+
+    async fn delete_policy(&self, id: &str) -> EdcResult<()>;
+}
+
+#[async_trait::async_trait]
+pub trait ContractDefinitionStore: Send + Sync {
+    async fn create_contract_definition(
+        &self,
+        contract_definition: &NewContractDefinition,
+    ) -> EdcResult<IdResponse<String>>;
+
+    async fn get_contract_definition(&self, id: &str) -> EdcResult<ContractDefinition>;
+
+    async fn update_contract_definition(
+        &self,
+        contract_definition: &ContractDefinition,
+    ) -> EdcResult<()>;
+
+    async fn upsert_contract_definition(
+        &self,
+        contract_definition: &NewContractDefinition,
+    ) -> EdcResult<UpsertOutcome>;
+
+    async fn query_contract_definitions(&self, query: Query) -> EdcResult<Vec<ContractDefinition>>;
+
+    /// Fetches every page of the query.
+    async fn query_all_contract_definitions(
+        &self,
+        query: Query,
+    ) -> EdcResult<Vec<ContractDefinition>> {
+        paginate(query, |query| self.query_contract_definitions(query))
+            .try_collect()
+            .await
+    }
+
+    async fn delete_contract_definition(&self, id: &str) -> EdcResult<()>;
+}
+
+#[async_trait::async_trait]
+pub trait CatalogService: Send + Sync {
+    async fn request_catalog(&self, request: &CatalogRequest) -> EdcResult<Catalog>;
+
+    /// Pages through the catalog, returning the datasets of every page and
+    /// of the sub-catalogs.
+    async fn request_all_datasets(&self, request: &CatalogRequest) -> EdcResult<Vec<Dataset>> {
+        paginate_catalog(request.clone(), |request| async move {
+            self.request_catalog(&request).await
+        })
+        .try_collect()
+        .await
+    }
+
+    async fn request_dataset(&self, request: &DatasetRequest) -> EdcResult<Dataset>;
+}
+
+#[async_trait::async_trait]
+pub trait NegotiationService: Send + Sync {
+    async fn initiate_negotiation(
+        &self,
+        contract_request: &ContractRequest,
+    ) -> EdcResult<IdResponse<String>>;
+
+    async fn get_negotiation(&self, id: &str) -> EdcResult<ContractNegotiation>;
+
+    async fn get_negotiation_state(&self, id: &str) -> EdcResult<ContractNegotiationState>;
+
+    async fn get_negotiation_agreement(&self, id: &str) -> EdcResult<ContractAgreement>;
+
+    /// Polls the negotiation until it reaches `target`, failing if it ends in
+    /// another final state or when the timeout of `options` elapses.
+    async fn wait_for_negotiation_state(
+        &self,
+        id: &str,
+        target: ContractNegotiationState,
+        options: &WaitOptions,
+    ) -> EdcResult<ContractNegotiation> {
+        wait_until(
+            format!("contract negotiation {}", id),
+            watch(|| self.get_negotiation(id), options),
+            options,
+            |state| state == &target,
+        )
+        .await
+    }
+
+    async fn terminate_negotiation(&self, id: &str, reason: &str) -> EdcResult<()>;
+
+    async fn query_negotiations(&self, query: Query) -> EdcResult<Vec<ContractNegotiation>>;
+
+    /// Fetches every page of the query.
+    async fn query_all_negotiations(&self, query: Query) -> EdcResult<Vec<ContractNegotiation>> {
+        paginate(query, |query| self.query_negotiations(query))
+            .try_collect()
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+pub trait AgreementStore: Send + Sync {
+    async fn get_agreement(&self, id: &str) -> EdcResult<ContractAgreement>;
+
+    async fn get_agreement_negotiation(&self, id: &str) -> EdcResult<ContractNegotiation>;
+
+    async fn query_agreements(&self, query: Query) -> EdcResult<Vec<ContractAgreement>>;
+
+    /// Fetches every page of the query.
+    async fn query_all_agreements(&self, query: Query) -> EdcResult<Vec<ContractAgreement>> {
+        paginate(query, |query| self.query_agreements(query))
+            .try_collect()
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+pub trait TransferService: Send + Sync {
+    async fn initiate_transfer(
+        &self,
+        transfer_request: &TransferRequest,
+    ) -> EdcResult<IdResponse<String>>;
+
+    async fn get_transfer(&self, id: &str) -> EdcResult<TransferProcess>;
+
+    async fn get_transfer_state(&self, id: &str) -> EdcResult<TransferProcessState>;
+
+    /// Polls the transfer until it reaches `target`, failing if it ends in
+    /// another final state or when the timeout of `options` elapses.
+    async fn wait_for_transfer_state(
+        &self,
+        id: &str,
+        target: TransferProcessState,
+        options: &WaitOptions,
+    ) -> EdcResult<TransferProcess> {
+        wait_until(
+            format!("transfer process {}", id),
+            watch(|| self.get_transfer(id), options),
+            options,
+            |state| state == &target,
+        )
+        .await
+    }
+
+    async fn terminate_transfer(&self, id: &str, reason: &str) -> EdcResult<()>;
+
+    async fn suspend_transfer(&self, id: &str, reason: &str) -> EdcResult<()>;
+
+    async fn resume_transfer(&self, id: &str) -> EdcResult<()>;
+
+    async fn query_transfers(&self, query: Query) -> EdcResult<Vec<TransferProcess>>;
+
+    /// Fetches every page of the query.
+    async fn query_all_transfers(&self, query: Query) -> EdcResult<Vec<TransferProcess>> {
+        paginate(query, |query| self.query_transfers(query))
+            .try_collect()
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+pub trait EdrStore: Send + Sync {
+    /// The EDR entry of a transfer process.
+    async fn get_edr(&self, transfer_process_id: &str) -> EdcResult<EndpointDataReferenceEntry>;
+
+    async fn get_edr_data_address(&self, transfer_process_id: &str) -> EdcResult<DataAddress>;
+
+    async fn refresh_edr(&self, transfer_process_id: &str) -> EdcResult<DataAddress>;
+
+    async fn query_edrs(&self, query: Query) -> EdcResult<Vec<EndpointDataReferenceEntry>>;
+
+    /// Fetches every page of the query.
+    async fn query_all_edrs(&self, query: Query) -> EdcResult<Vec<EndpointDataReferenceEntry>> {
+        paginate(query, |query| self.query_edrs(query))
+            .try_collect()
+            .await
+    }
+
+    async fn delete_edr(&self, transfer_process_id: &str) -> EdcResult<()>;
+}
+
+#[async_trait::async_trait]
+pub trait DataPlaneRegistry: Send + Sync {
+    async fn list_data_planes(&self) -> EdcResult<Vec<DataPlaneInstance>>;
+}
+
+#[async_trait::async_trait]
+pub trait SecretStore: Send + Sync {
+    async fn create_secret(&self, secret: &NewSecret) -> EdcResult<IdResponse<String>>;
+
+    async fn get_secret(&self, id: &str) -> EdcResult<Secret>;
+
+    async fn update_secret(&self, secret: &Secret) -> EdcResult<()>;
+
+    async fn delete_secret(&self, id: &str) -> EdcResult<()>;
+}
+
+#[async_trait::async_trait]
+impl AssetStore for EdcConnectorClient {
+    async fn create_asset(&self, asset: &NewAsset) -> EdcResult<IdResponse<String>> {
+        self.assets().create(asset).await
+    }
+
+    async fn get_asset(&self, id: &str) -> EdcResult<Asset> {
+        self.assets().get(id).await
+    }
+
+    async fn update_asset(&self, asset: &Asset) -> EdcResult<()> {
+        self.assets().update(asset).await
+    }
+
+    async fn upsert_asset(&self, asset: &NewAsset) -> EdcResult<UpsertOutcome> {
+        self.assets().upsert(asset).await
+    }
+
+    async fn query_assets(&self, query: Query) -> EdcResult<Vec<Asset>> {
+        self.assets().query(query).await
+    }
+
+    async fn delete_asset(&self, id: &str) -> EdcResult<()> {
+        self.assets().delete(id).await
+    }
+}
+
+#[async_trait::async_trait]
+impl PolicyStore for EdcConnectorClient {
+    async fn create_policy(
+        &self,
+        policy_definition: &NewPolicyDefinition,
+    ) -> EdcResult<IdResponse<String>> {
+        self.policies().create(policy_definition).await
+    }
+
+    async fn get_policy(&self, id: &str) -> EdcResult<PolicyDefinition> {
+        self.policies().get(id).await
+    }
+
+    async fn update_policy(&self, policy_definition: &PolicyDefinition) -> EdcResult<()> {
+        self.policies().update(policy_definition).await
+    }
+
+    async fn upsert_policy(
+        &self,
+        policy_definition: &NewPolicyDefinition,
+    ) -> EdcResult<UpsertOutcome> {
+        self.policies().upsert(policy_definition).await
+    }
+
+    async fn query_policies(&self, query: Query) -> EdcResult<Vec<PolicyDefinition>> {
+        self.policies().query(query).await
+    }
+
+    async fn validate_policy(&self, id: &str) -> EdcResult<PolicyValidationResult> {
+        self.policies().validate(id).await
+    }
+
+    async fn policy_evaluation_plan(
+        &self,
+        id: &str,
+        scope: &str,
+    ) -> EdcResult<PolicyEvaluationPlan> {
+        self.policies().evaluation_plan(id, scope).await
+    }
+
+    async fn delete_policy(&self, id: &str) -> EdcResult<()> {
+        self.policies().delete(id).await
+    }
+}
+
+#[async_trait::async_trait]
+impl ContractDefinitionStore for EdcConnectorClient {
+    async fn create_contract_definition(
+        &self,
+        contract_definition: &NewContractDefinition,
+    ) -> EdcResult<IdResponse<String>> {
+        self.contract_definitions()
+            .create(contract_definition)
+            .await
+    }
+
+    async fn get_contract_definition(&self, id: &str) -> EdcResult<ContractDefinition> {
+        self.contract_definitions().get(id).await
+    }
+
+    async fn update_contract_definition(
+        &self,
+        contract_definition: &ContractDefinition,
+    ) -> EdcResult<()> {
+        self.contract_definitions()
+            .update(contract_definition)
+            .await
+    }
+
+    async fn upsert_contract_definition(
+        &self,
+        contract_definition: &NewContractDefinition,
+    ) -> EdcResult<UpsertOutcome> {
+        self.contract_definitions()
+            .upsert(contract_definition)
+            .await
+    }
+
+    async fn query_contract_definitions(&self, query: Query) -> EdcResult<Vec<ContractDefinition>> {
+        self.contract_definitions().query(query).await
+    }
+
+    async fn delete_contract_definition(&self, id: &str) -> EdcResult<()> {
+        self.contract_definitions().delete(id).await
+    }
+}
+
+#[async_trait::async_trait]
+impl CatalogService for EdcConnectorClient {
+    async fn request_catalog(&self, request: &CatalogRequest) -> EdcResult<Catalog> {
+        self.catalogue().request(request).await
+    }
+
+    async fn request_dataset(&self, request: &DatasetRequest) -> EdcResult<Dataset> {
+        self.catalogue().dataset(request).await
+    }
+}
+
+#[async_trait::async_trait]
+impl NegotiationService for EdcConnectorClient {
+    async fn initiate_negotiation(
+        &self,
+        contract_request: &ContractRequest,
+    ) -> EdcResult<IdResponse<String>> {
+        self.contract_negotiations()
+            .initiate(contract_request)
+            .await
+    }
+
+    async fn get_negotiation(&self, id: &str) -> EdcResult<ContractNegotiation> {
+        self.contract_negotiations().get(id).await
+    }
+
+    async fn get_negotiation_state(&self, id: &str) -> EdcResult<ContractNegotiationState> {
+        self.contract_negotiations().get_state(id).await
+    }
+
+    async fn get_negotiation_agreement(&self, id: &str) -> EdcResult<ContractAgreement> {
+        self.contract_negotiations().get_agreement(id).await
+    }
+
+    async fn terminate_negotiation(&self, id: &str, reason: &str) -> EdcResult<()> {
+        self.contract_negotiations().terminate(id, reason).await
+    }
+
+    async fn query_negotiations(&self, query: Query) -> EdcResult<Vec<ContractNegotiation>> {
+        self.contract_negotiations().query(query).await
+    }
+}
+
+#[async_trait::async_trait]
+impl AgreementStore for EdcConnectorClient {
+    async fn get_agreement(&self, id: &str) -> EdcResult<ContractAgreement> {
+        self.contract_agreements().get(id).await
+    }
+
+    async fn get_agreement_negotiation(&self, id: &str) -> EdcResult<ContractNegotiation> {
+        self.contract_agreements().get_negotiation(id).await
+    }
+
+    async fn query_agreements(&self, query: Query) -> EdcResult<Vec<ContractAgreement>> {
+        self.contract_agreements().query(query).await
+    }
+}
+
+#[async_trait::async_trait]
+impl TransferService for EdcConnectorClient {
+    async fn initiate_transfer(
+        &self,
+        transfer_request: &TransferRequest,
+    ) -> EdcResult<IdResponse<String>> {
+        self.transfer_processes().initiate(transfer_request).await
+    }
+
+    async fn get_transfer(&self, id: &str) -> EdcResult<TransferProcess> {
+        self.transfer_processes().get(id).await
+    }
+
+    async fn get_transfer_state(&self, id: &str) -> EdcResult<TransferProcessState> {
+        self.transfer_processes().get_state(id).await
+    }
+
+    async fn terminate_transfer(&self, id: &str, reason: &str) -> EdcResult<()> {
+        self.transfer_processes().terminate(id, reason).await
+    }
+
+    async fn suspend_transfer(&self, id: &str, reason: &str) -> EdcResult<()> {
+        self.transfer_processes().suspend(id, reason).await
+    }
+
+    async fn resume_transfer(&self, id: &str) -> EdcResult<()> {
+        self.transfer_processes().resume(id).await
+    }
+
+    async fn query_transfers(&self, query: Query) -> EdcResult<Vec<TransferProcess>> {
+        self.transfer_processes().query(query).await
+    }
+}
+
+#[async_trait::async_trait]
+impl EdrStore for EdcConnectorClient {
+    async fn get_edr(&self, transfer_process_id: &str) -> EdcResult<EndpointDataReferenceEntry> {
+        self.edrs().get_entry(transfer_process_id).await
+    }
+
+    async fn get_edr_data_address(&self, transfer_process_id: &str) -> EdcResult<DataAddress> {
+        self.edrs().get_data_address(transfer_process_id).await
+    }
+
+    async fn refresh_edr(&self, transfer_process_id: &str) -> EdcResult<DataAddress> {
+        self.edrs().refresh(transfer_process_id).await
+    }
+
+    async fn query_edrs(&self, query: Query) -> EdcResult<Vec<EndpointDataReferenceEntry>> {
+        self.edrs().query(query).await
+    }
+
+    async fn delete_edr(&self, transfer_process_id: &str) -> EdcResult<()> {
+        self.edrs().delete(transfer_process_id).await
+    }
+}
+
+#[async_trait::async_trait]
+impl DataPlaneRegistry for EdcConnectorClient {
+    async fn list_data_planes(&self) -> EdcResult<Vec<DataPlaneInstance>> {
+        self.data_planes().list().await
+    }
+}
+
+#[async_trait::async_trait]
+impl SecretStore for EdcConnectorClient {
+    async fn create_secret(&self, secret: &NewSecret) -> EdcResult<IdResponse<String>> {
+        self.secrets().create(secret).await
+    }
+
+    async fn get_secret(&self, id: &str) -> EdcResult<Secret> {
+        self.secrets().get(id).await
+    }
+
+    async fn update_secret(&self, secret: &Secret) -> EdcResult<()> {
+        self.secrets().update(secret).await
+    }
+
+    async fn delete_secret(&self, id: &str) -> EdcResult<()> {
+        self.secrets().delete(id).await
+    }
+}
@@ -1,4 +1,10 @@
+use futures::{Stream, TryStreamExt};
+
 use crate::{
+    api::{
+        pagination::paginate,
+        wait::{wait_until, watch, WaitOptions},
+    },
     client::EdcConnectorClientInternal,
     types::{
         context::{WithContext, WithContextRef},
@@ -49,10 +55,41 @@ impl<'a> TransferProcessApi<'a> {
             .map(|ctx| ctx.inner.state().clone())
     }
 
+    pub async fn wait_for_state(
+        &self,
+        id: &str,
+        target: TransferProcessState,
+        options: &WaitOptions,
+    ) -> EdcResult<TransferProcess> {
+        wait_until(
+            format!("transfer process {}", id),
+            self.watch(id, options),
+            options,
+            |state| state == &target,
+        )
+        .await
+    }
+
+    pub fn watch(
+        &self,
+        id: &str,
+        options: &WaitOptions,
+    ) -> impl Stream<Item = EdcResult<TransferProcess>> + 'a {
+        let client = self.0;
+        let id = id.to_string();
+        watch(
+            move || {
+                let id = id.clone();
+                async move { TransferProcessApi(client).get(&id).await }
+            },
+            options,
+        )
+    }
+
     pub async fn query(&self, query: Query) -> EdcResult<Vec<TransferProcess>> {
         let url = format!("{}/v3/transferprocesses/request", self.0.management_url);
         self.0
-            .post::<_, Vec<WithContext<TransferProcess>>>(
+            .query::<_, Vec<WithContext<TransferProcess>>>(
                 url,
                 &WithContextRef::default_context(&query),
             )
@@ -60,6 +97,20 @@ impl<'a> TransferProcessApi<'a> {
             .map(|results| results.into_iter().map(|ctx| ctx.inner).collect())
     }
 
+    pub fn query_stream(
+        &self,
+        query: Query,
+    ) -> impl Stream<Item = EdcResult<TransferProcess>> + 'a {
+        let client = self.0;
+        paginate(query, move |query| async move {
+            TransferProcessApi(client).query(query).await
+        })
+    }
+
+    pub async fn query_all(&self, query: Query) -> EdcResult<Vec<TransferProcess>> {
+        self.query_stream(query).try_collect().await
+    }
+
     pub async fn terminate(&self, id: &str, reason: &str) -> EdcResult<()> {
         let url = format!(
             "{}/v3/transferprocesses/{}/terminate",
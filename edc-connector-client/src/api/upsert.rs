@@ -0,0 +1,110 @@
+use serde::Serialize;
+use serde_json::Value;
+
+/// Result of an upsert.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpsertOutcome {
+    Created,
+    Updated,
+    /// The connector already holds the desired state, nothing was sent.
+    Unchanged,
+}
+
+/// Values added by the connector to stored assets.
+pub(crate) const ASSET_MANAGED_VALUES: &[&str] = &["/properties/id", "/dataAddress/@type"];
+
+/// Values added by the connector to stored policy definitions.
+pub(crate) const POLICY_DEFINITION_MANAGED_VALUES: &[&str] = &["/policy/@id"];
+
+/// Compares the desired state with the one returned by the connector.
+///
+/// `managed` are JSON pointers to values the connector fills in on its own,
+/// e.g. the `id` property of assets, ignored unless they are part of the
+/// desired state. Single element arrays are equal to their element since
+/// JSON-LD compaction collapses them.
+pub(crate) fn is_unchanged<D, C>(desired: &D, current: &C, managed: &[&str]) -> bool
+where
+    D: Serialize,
+    C: Serialize,
+{
+    let (Ok(desired), Ok(mut current)) =
+        (serde_json::to_value(desired), serde_json::to_value(current))
+    else {
+        return false;
+    };
+
+    for pointer in managed {
+        if desired.pointer(pointer).is_none() {
+            remove(&mut current, pointer);
+        }
+    }
+
+    normalize(without_id(desired)) == normalize(without_id(current))
+}
+
+fn without_id(mut value: Value) -> Value {
+    if let Value::Object(object) = &mut value {
+        object.remove("@id");
+    }
+    value
+}
+
+fn remove(value: &mut Value, pointer: &str) {
+    let Some((parent, key)) = pointer.rsplit_once('/') else {
+        return;
+    };
+    if let Some(Value::Object(object)) = value.pointer_mut(parent) {
+        object.remove(key);
+    }
+}
+
+fn normalize(value: Value) -> Value {
+    match value {
+        Value::Array(mut values) if values.len() == 1 => normalize(values.remove(0)),
+        Value::Array(values) => Value::Array(values.into_iter().map(normalize).collect()),
+        Value::Object(object) => Value::Object(
+            object
+                .into_iter()
+                .filter(|(_, value)| !value.is_null())
+                .map(|(key, value)| (key, normalize(value)))
+                .collect(),
+        ),
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::is_unchanged;
+
+    #[test]
+    fn should_ignore_managed_and_compacted_values() {
+        let desired = json!({
+            "@id": "1",
+            "properties": { "name": "report", "tags": ["finance"] },
+            "dataAddress": { "type": "HttpData" }
+        });
+        let current = json!({
+            "@id": "1",
+            "properties": { "id": "1", "name": "report", "tags": "finance" },
+            "dataAddress": { "@type": "DataAddress", "type": "HttpData" }
+        });
+
+        assert!(is_unchanged(
+            &desired,
+            &current,
+            &["/properties/id", "/dataAddress/@type"]
+        ));
+        assert!(!is_unchanged(&desired, &current, &["/properties/id"]));
+    }
+
+    #[test]
+    fn should_detect_changed_values() {
+        let desired = json!({ "properties": { "name": "report" } });
+        let current = json!({ "properties": { "name": "draft" } });
+
+        assert!(!is_unchanged(&desired, &current, &[]));
+    }
+}
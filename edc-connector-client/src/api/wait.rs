@@ -0,0 +1,299 @@
+use std::{fmt::Debug, future::Future, time::Duration};
+
+use futures::{stream, Stream, TryStreamExt};
+
+use crate::{
+    types::{
+        contract_negotiation::{ContractNegotiation, ContractNegotiationState},
+        transfer_process::{TransferProcess, TransferProcessState},
+    },
+    EdcResult, Error, WorkflowError,
+};
+
+/// Polling strategy used when waiting for, or watching, the state of a
+/// contract negotiation or a transfer process.
+///
+/// The interval between two polls grows by `multiplier` up to `max_interval`
+/// while the state does not change, and is reset on every transition.
+#[derive(Clone, Debug)]
+pub struct WaitOptions {
+    interval: Duration,
+    max_interval: Duration,
+    multiplier: f64,
+    timeout: Duration,
+}
+
+impl WaitOptions {
+    pub fn builder() -> WaitOptionsBuilder {
+        WaitOptionsBuilder(WaitOptions::default())
+    }
+
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    pub fn max_interval(&self) -> Duration {
+        self.max_interval
+    }
+
+    pub fn multiplier(&self) -> f64 {
+        self.multiplier
+    }
+
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    fn next_interval(&self, interval: Duration) -> Duration {
+        let secs = (interval.as_secs_f64() * self.multiplier).min(self.max_interval.as_secs_f64());
+        Duration::try_from_secs_f64(secs).unwrap_or(self.max_interval)
+    }
+}
+
+impl Default for WaitOptions {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_millis(500),
+            max_interval: Duration::from_secs(5),
+            multiplier: 1.5,
+            timeout: Duration::from_secs(60),
+        }
+    }
+}
+
+pub struct WaitOptionsBuilder(WaitOptions);
+
+impl WaitOptionsBuilder {
+    /// Delay before the first poll following a state transition.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.0.interval = interval;
+        self
+    }
+
+    pub fn max_interval(mut self, max_interval: Duration) -> Self {
+        self.0.max_interval = max_interval;
+        self
+    }
+
+    /// Use `1.0` to poll at a fixed interval. Values below `1.0`, infinite
+    /// or NaN fall back to `1.0`.
+    pub fn multiplier(mut self, multiplier: f64) -> Self {
+        self.0.multiplier = if multiplier.is_finite() {
+            multiplier.max(1.0)
+        } else {
+            1.0
+        };
+        self
+    }
+
+    /// Maximum time spent waiting for a state. Not applied to watch streams.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.0.timeout = timeout;
+        self
+    }
+
+    pub fn build(self) -> WaitOptions {
+        self.0
+    }
+}
+
+/// A resource driven by a state machine on the connector.
+pub(crate) trait Process {
+    type State: Clone + Debug + PartialEq;
+
+    fn current_state(&self) -> &Self::State;
+
+    /// No further transition is expected once this returns `true`.
+    fn is_final(&self) -> bool;
+
+    fn terminated(&self) -> Option<Error>;
+}
+
+impl Process for ContractNegotiation {
+    type State = ContractNegotiationState;
+
+    fn current_state(&self) -> &ContractNegotiationState {
+        self.state()
+    }
+
+    fn is_final(&self) -> bool {
+        matches!(
+            self.state(),
+            ContractNegotiationState::Finalized | ContractNegotiationState::Terminated
+        )
+    }
+
+    fn terminated(&self) -> Option<Error> {
+        (self.state() == &ContractNegotiationState::Terminated).then(|| {
+            Error::Workflow(WorkflowError::NegotiationTerminated {
+                id: self.id().to_string(),
+                error_detail: self.error_detail().cloned(),
+            })
+        })
+    }
+}
+
+impl Process for TransferProcess {
+    type State = TransferProcessState;
+
+    fn current_state(&self) -> &TransferProcessState {
+        self.state()
+    }
+
+    fn is_final(&self) -> bool {
+        matches!(
+            self.state(),
+            TransferProcessState::Completed
+                | TransferProcessState::Terminated
+                | TransferProcessState::Deprovisioned
+        )
+    }
+
+    fn terminated(&self) -> Option<Error> {
+        (self.state() == &TransferProcessState::Terminated).then(|| {
+            Error::Workflow(WorkflowError::TransferTerminated {
+                id: self.id().to_string(),
+                error_detail: self.error_detail().cloned(),
+            })
+        })
+    }
+}
+
+/// Polls `fetch` and yields the process every time its state changes, ending
+/// after a final state or the first error.
+pub(crate) fn watch<'a, T, F, Fut>(
+    fetch: F,
+    options: &WaitOptions,
+) -> impl Stream<Item = EdcResult<T>> + 'a
+where
+    T: Process + 'a,
+    F: Fn() -> Fut + 'a,
+    Fut: Future<Output = EdcResult<T>> + 'a,
+{
+    let options = options.clone();
+    stream::unfold(
+        Some((fetch, None::<T::State>, None::<Duration>)),
+        move |state| {
+            let options = options.clone();
+            async move {
+                let (fetch, last, mut delay) = state?;
+                loop {
+                    if let Some(delay) = delay {
+                        tokio::time::sleep(delay).await;
+                    }
+
+                    let process = match fetch().await {
+                        Ok(process) => process,
+                        Err(err) => return Some((Err(err), None)),
+                    };
+
+                    if last.as_ref() != Some(process.current_state()) {
+                        let next = (!process.is_final()).then(|| {
+                            (
+                                fetch,
+                                Some(process.current_state().clone()),
+                                Some(options.interval),
+                            )
+                        });
+                        return Some((Ok(process), next));
+                    }
+
+                    delay = delay.map(|delay| options.next_interval(delay));
+                }
+            }
+        },
+    )
+}
+
+/// Consumes a [`watch`] stream until a state accepted by `accept` is observed.
+///
+/// Fails fast when the process is terminated or reaches a final state that is
+/// not accepted, and after `options.timeout`.
+pub(crate) async fn wait_until<T, S>(
+    subject: String,
+    watch: S,
+    options: &WaitOptions,
+    accept: impl Fn(&T::State) -> bool,
+) -> EdcResult<T>
+where
+    T: Process,
+    S: Stream<Item = EdcResult<T>>,
+{
+    let wait = async {
+        futures::pin_mut!(watch);
+        while let Some(process) = watch.try_next().await? {
+            if accept(process.current_state()) {
+                return Ok(process);
+            }
+
+            if let Some(err) = process.terminated() {
+                return Err(err);
+            }
+
+            if process.is_final() {
+                return Err(Error::Workflow(WorkflowError::UnexpectedState {
+                    subject: subject.clone(),
+                    state: format!("{:?}", process.current_state()),
+                }));
+            }
+        }
+        Err(Error::Workflow(WorkflowError::WatchEnded(subject.clone())))
+    };
+
+    tokio::time::timeout(options.timeout, wait)
+        .await
+        .map_err(|_| Error::Workflow(WorkflowError::Timeout(subject)))?
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use futures::stream;
+
+    use crate::{types::contract_negotiation::ContractNegotiation, Error, WorkflowError};
+
+    use super::{wait_until, WaitOptions};
+
+    #[tokio::test]
+    async fn should_fail_when_the_watch_stream_ends() {
+        let result = wait_until::<ContractNegotiation, _>(
+            "contract negotiation 1".to_string(),
+            stream::empty(),
+            &WaitOptions::default(),
+            |_| false,
+        )
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(Error::Workflow(WorkflowError::WatchEnded(_)))
+        ));
+    }
+
+    #[test]
+    fn should_cap_the_interval_with_a_huge_multiplier() {
+        let options = WaitOptions::builder()
+            .interval(Duration::from_secs(1))
+            .max_interval(Duration::from_secs(5))
+            .multiplier(1e20)
+            .build();
+
+        assert_eq!(
+            options.next_interval(Duration::from_secs(1)),
+            Duration::from_secs(5)
+        );
+        assert_eq!(options.next_interval(Duration::MAX), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn should_poll_at_a_fixed_interval_with_an_infinite_multiplier() {
+        let options = WaitOptions::builder().multiplier(f64::INFINITY).build();
+
+        assert_eq!(options.multiplier(), 1.0);
+        assert_eq!(
+            options.next_interval(Duration::from_secs(1)),
+            Duration::from_secs(1)
+        );
+    }
+}
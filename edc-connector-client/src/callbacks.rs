@@ -0,0 +1,169 @@
+//! Receiver for the events the connector delivers to the callback addresses
+//! registered on contract and transfer requests.
+//!
+//! Enabled with the `callbacks` feature.
+//!
+//! ```rust,no_run
+//! use edc_connector_client::callbacks::{CallbackReceiver, Event, EventEnvelope, EventHandler, HandlerError};
+//!
+//! struct Printer;
+//!
+//! #[async_trait::async_trait]
+//! impl EventHandler for Printer {
+//!     async fn handle(&self, envelope: EventEnvelope) -> Result<(), HandlerError> {
+//!         if let Event::TransferProcessStarted(event) = envelope.event() {
+//!             println!("Transfer {} started", event.transfer_process_id());
+//!         }
+//!         Ok(())
+//!     }
+//! }
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let receiver = CallbackReceiver::builder()
+//!         .handler(Printer)
+//!         .auth("X-Api-Key", "secret")
+//!         .build()?;
+//!
+//!     let listener = tokio::net::TcpListener::bind("0.0.0.0:8080").await?;
+//!     axum::serve(listener, receiver.into_router()).await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod events;
+
+use std::sync::Arc;
+
+use axum::{
+    body::Bytes, extract::State, http::HeaderMap, http::StatusCode, response::IntoResponse,
+    routing::post, Router,
+};
+
+use crate::BuilderError;
+
+pub use self::events::{ContractNegotiationEvent, Event, EventEnvelope, TransferProcessEvent};
+
+pub type HandlerError = Box<dyn std::error::Error + Send + Sync>;
+
+/// User handler invoked for every event received.
+///
+/// Returning an error answers the connector with `500`, which makes it
+/// retry the delivery of transactional callbacks.
+#[async_trait::async_trait]
+pub trait EventHandler: Send + Sync {
+    async fn handle(&self, envelope: EventEnvelope) -> Result<(), HandlerError>;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CallbackError {
+    #[error("Missing or invalid callback authentication")]
+    Unauthorized,
+
+    #[error("Malformed event envelope")]
+    Malformed(#[from] serde_json::Error),
+
+    #[error("Event handler failed")]
+    Handler(#[source] HandlerError),
+}
+
+impl CallbackError {
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            CallbackError::Unauthorized => StatusCode::UNAUTHORIZED,
+            CallbackError::Malformed(_) => StatusCode::BAD_REQUEST,
+            CallbackError::Handler(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+/// Header and secret matching the `authKey` and the vault entry referenced by
+/// `authCodeId` of a [`CallbackAddress`](crate::types::callback_address::CallbackAddress).
+#[derive(Clone)]
+struct CallbackAuth {
+    key: String,
+    code: String,
+}
+
+#[derive(Clone)]
+pub struct CallbackReceiver {
+    handler: Arc<dyn EventHandler>,
+    auth: Option<CallbackAuth>,
+}
+
+impl CallbackReceiver {
+    pub fn builder() -> CallbackReceiverBuilder {
+        CallbackReceiverBuilder::default()
+    }
+
+    /// Authenticates and decodes a delivery and dispatches it to the handler.
+    ///
+    /// Can be used to mount the receiver in any HTTP framework.
+    pub async fn handle(&self, headers: &HeaderMap, body: &[u8]) -> Result<(), CallbackError> {
+        if let Some(auth) = &self.auth {
+            let authorized = headers
+                .get(&auth.key)
+                .map(|value| value.as_bytes() == auth.code.as_bytes())
+                .unwrap_or(false);
+
+            if !authorized {
+                return Err(CallbackError::Unauthorized);
+            }
+        }
+
+        let envelope = serde_json::from_slice::<EventEnvelope>(body)?;
+
+        self.handler
+            .handle(envelope)
+            .await
+            .map_err(CallbackError::Handler)
+    }
+
+    /// An `axum` router accepting deliveries with `POST /`.
+    pub fn into_router(self) -> Router {
+        Router::new().route("/", post(receive)).with_state(self)
+    }
+}
+
+async fn receive(
+    State(receiver): State<CallbackReceiver>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> impl IntoResponse {
+    match receiver.handle(&headers, &body).await {
+        Ok(()) => StatusCode::NO_CONTENT,
+        Err(err) => err.status_code(),
+    }
+}
+
+#[derive(Default)]
+pub struct CallbackReceiverBuilder {
+    handler: Option<Arc<dyn EventHandler>>,
+    auth: Option<CallbackAuth>,
+}
+
+impl CallbackReceiverBuilder {
+    pub fn handler(mut self, handler: impl EventHandler + 'static) -> Self {
+        self.handler = Some(Arc::new(handler));
+        self
+    }
+
+    /// Requires deliveries to carry the header `auth_key` with value `auth_code`.
+    pub fn auth(mut self, auth_key: &str, auth_code: &str) -> Self {
+        self.auth = Some(CallbackAuth {
+            key: auth_key.to_string(),
+            code: auth_code.to_string(),
+        });
+        self
+    }
+
+    pub fn build(self) -> Result<CallbackReceiver, BuilderError> {
+        Ok(CallbackReceiver {
+            handler: self
+                .handler
+                .ok_or_else(|| BuilderError::missing_property("handler"))?,
+            auth: self.auth,
+        })
+    }
+}
@@ -0,0 +1,447 @@
+use serde::{de::Error as _, Deserialize, Deserializer};
+use serde_json::Value;
+use serde_with::{formats::PreferMany, serde_as, OneOrMany};
+
+use crate::{
+    types::{
+        callback_address::CallbackAddress,
+        properties::{FromValue, Properties},
+        transfer_process::TransferProcessKind,
+    },
+    ConversionError,
+};
+
+/// Envelope of an event delivered by the connector to a callback address.
+#[derive(Debug, Clone)]
+pub struct EventEnvelope {
+    id: String,
+    at: i64,
+    event: Event,
+}
+
+impl EventEnvelope {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Epoch millis at which the event was emitted.
+    pub fn at(&self) -> i64 {
+        self.at
+    }
+
+    pub fn event(&self) -> &Event {
+        &self.event
+    }
+
+    pub fn into_event(self) -> Event {
+        self.event
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Event {
+    ContractNegotiationInitiated(ContractNegotiationEvent),
+    ContractNegotiationRequested(ContractNegotiationEvent),
+    ContractNegotiationOffered(ContractNegotiationEvent),
+    ContractNegotiationAccepted(ContractNegotiationEvent),
+    ContractNegotiationAgreed(ContractNegotiationEvent),
+    ContractNegotiationVerified(ContractNegotiationEvent),
+    ContractNegotiationFinalized(ContractNegotiationEvent),
+    ContractNegotiationTerminated(ContractNegotiationEvent),
+    TransferProcessInitiated(TransferProcessEvent),
+    TransferProcessProvisioningRequested(TransferProcessEvent),
+    TransferProcessProvisioned(TransferProcessEvent),
+    TransferProcessRequested(TransferProcessEvent),
+    TransferProcessStarted(TransferProcessEvent),
+    TransferProcessSuspended(TransferProcessEvent),
+    TransferProcessCompleted(TransferProcessEvent),
+    TransferProcessTerminated(TransferProcessEvent),
+    TransferProcessDeprovisioningRequested(TransferProcessEvent),
+    TransferProcessDeprovisioned(TransferProcessEvent),
+    /// Any event not modelled above, e.g. `asset.created`.
+    Other {
+        kind: String,
+        payload: Value,
+    },
+}
+
+/// Class names of the modelled events, with their names as used in
+/// [`CallbackAddress`] events.
+const EVENT_NAMES: &[(&str, &str)] = &[
+    (
+        "ContractNegotiationInitiated",
+        "contract.negotiation.initiated",
+    ),
+    (
+        "ContractNegotiationRequested",
+        "contract.negotiation.requested",
+    ),
+    ("ContractNegotiationOffered", "contract.negotiation.offered"),
+    (
+        "ContractNegotiationAccepted",
+        "contract.negotiation.accepted",
+    ),
+    ("ContractNegotiationAgreed", "contract.negotiation.agreed"),
+    (
+        "ContractNegotiationVerified",
+        "contract.negotiation.verified",
+    ),
+    (
+        "ContractNegotiationFinalized",
+        "contract.negotiation.finalized",
+    ),
+    (
+        "ContractNegotiationTerminated",
+        "contract.negotiation.terminated",
+    ),
+    ("TransferProcessInitiated", "transfer.process.initiated"),
+    (
+        "TransferProcessProvisioningRequested",
+        "transfer.process.provisioningRequested",
+    ),
+    ("TransferProcessProvisioned", "transfer.process.provisioned"),
+    ("TransferProcessRequested", "transfer.process.requested"),
+    ("TransferProcessStarted", "transfer.process.started"),
+    ("TransferProcessSuspended", "transfer.process.suspended"),
+    ("TransferProcessCompleted", "transfer.process.completed"),
+    ("TransferProcessTerminated", "transfer.process.terminated"),
+    (
+        "TransferProcessDeprovisioningRequested",
+        "transfer.process.deprovisioningRequested",
+    ),
+    (
+        "TransferProcessDeprovisioned",
+        "transfer.process.deprovisioned",
+    ),
+];
+
+impl Event {
+    /// The event name as used in [`CallbackAddress`] events,
+    /// e.g. `contract.negotiation.finalized`.
+    pub fn name(&self) -> String {
+        let kind = self.kind();
+        EVENT_NAMES
+            .iter()
+            .find(|(class, _)| *class == kind)
+            .map(|(_, name)| name.to_string())
+            .unwrap_or_else(|| event_name(kind))
+    }
+
+    fn kind(&self) -> &str {
+        match self {
+            Event::ContractNegotiationInitiated(_) => "ContractNegotiationInitiated",
+            Event::ContractNegotiationRequested(_) => "ContractNegotiationRequested",
+            Event::ContractNegotiationOffered(_) => "ContractNegotiationOffered",
+            Event::ContractNegotiationAccepted(_) => "ContractNegotiationAccepted",
+            Event::ContractNegotiationAgreed(_) => "ContractNegotiationAgreed",
+            Event::ContractNegotiationVerified(_) => "ContractNegotiationVerified",
+            Event::ContractNegotiationFinalized(_) => "ContractNegotiationFinalized",
+            Event::ContractNegotiationTerminated(_) => "ContractNegotiationTerminated",
+            Event::TransferProcessInitiated(_) => "TransferProcessInitiated",
+            Event::TransferProcessProvisioningRequested(_) => {
+                "TransferProcessProvisioningRequested"
+            }
+            Event::TransferProcessProvisioned(_) => "TransferProcessProvisioned",
+            Event::TransferProcessRequested(_) => "TransferProcessRequested",
+            Event::TransferProcessStarted(_) => "TransferProcessStarted",
+            Event::TransferProcessSuspended(_) => "TransferProcessSuspended",
+            Event::TransferProcessCompleted(_) => "TransferProcessCompleted",
+            Event::TransferProcessTerminated(_) => "TransferProcessTerminated",
+            Event::TransferProcessDeprovisioningRequested(_) => {
+                "TransferProcessDeprovisioningRequested"
+            }
+            Event::TransferProcessDeprovisioned(_) => "TransferProcessDeprovisioned",
+            Event::Other { kind, .. } => kind,
+        }
+    }
+
+    fn from_raw(kind: String, payload: Value) -> Result<Event, serde_json::Error> {
+        let event = match normalize_kind(&kind) {
+            "ContractNegotiationInitiated" => {
+                Event::ContractNegotiationInitiated(serde_json::from_value(payload)?)
+            }
+            "ContractNegotiationRequested" => {
+                Event::ContractNegotiationRequested(serde_json::from_value(payload)?)
+            }
+            "ContractNegotiationOffered" => {
+                Event::ContractNegotiationOffered(serde_json::from_value(payload)?)
+            }
+            "ContractNegotiationAccepted" => {
+                Event::ContractNegotiationAccepted(serde_json::from_value(payload)?)
+            }
+            "ContractNegotiationAgreed" => {
+                Event::ContractNegotiationAgreed(serde_json::from_value(payload)?)
+            }
+            "ContractNegotiationVerified" => {
+                Event::ContractNegotiationVerified(serde_json::from_value(payload)?)
+            }
+            "ContractNegotiationFinalized" => {
+                Event::ContractNegotiationFinalized(serde_json::from_value(payload)?)
+            }
+            "ContractNegotiationTerminated" => {
+                Event::ContractNegotiationTerminated(serde_json::from_value(payload)?)
+            }
+            "TransferProcessInitiated" => {
+                Event::TransferProcessInitiated(serde_json::from_value(payload)?)
+            }
+            "TransferProcessProvisioningRequested" => {
+                Event::TransferProcessProvisioningRequested(serde_json::from_value(payload)?)
+            }
+            "TransferProcessProvisioned" => {
+                Event::TransferProcessProvisioned(serde_json::from_value(payload)?)
+            }
+            "TransferProcessRequested" => {
+                Event::TransferProcessRequested(serde_json::from_value(payload)?)
+            }
+            "TransferProcessStarted" => {
+                Event::TransferProcessStarted(serde_json::from_value(payload)?)
+            }
+            "TransferProcessSuspended" => {
+                Event::TransferProcessSuspended(serde_json::from_value(payload)?)
+            }
+            "TransferProcessCompleted" => {
+                Event::TransferProcessCompleted(serde_json::from_value(payload)?)
+            }
+            "TransferProcessTerminated" => {
+                Event::TransferProcessTerminated(serde_json::from_value(payload)?)
+            }
+            "TransferProcessDeprovisioningRequested" => {
+                Event::TransferProcessDeprovisioningRequested(serde_json::from_value(payload)?)
+            }
+            "TransferProcessDeprovisioned" => {
+                Event::TransferProcessDeprovisioned(serde_json::from_value(payload)?)
+            }
+            _ => Event::Other { kind, payload },
+        };
+
+        Ok(event)
+    }
+}
+
+/// Accepts both the class name (`ContractNegotiationFinalized`) and the event
+/// name (`contract.negotiation.finalized`) and returns the former.
+fn normalize_kind(kind: &str) -> &str {
+    EVENT_NAMES
+        .iter()
+        .find(|(_, name)| *name == kind)
+        .map(|(class, _)| *class)
+        .unwrap_or(kind)
+}
+
+/// Best effort name for events that are not modelled, e.g. `AssetCreated`
+/// becomes `asset.created`.
+fn event_name(kind: &str) -> String {
+    if kind.contains('.') {
+        return kind.to_string();
+    }
+
+    let mut name = String::new();
+    for (i, c) in kind.chars().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            name.push('.');
+        }
+        name.extend(c.to_lowercase());
+    }
+    name
+}
+
+impl<'de> Deserialize<'de> for EventEnvelope {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct RawEnvelope {
+            id: String,
+            at: i64,
+            #[serde(rename = "type")]
+            kind: String,
+            #[serde(default)]
+            payload: Value,
+        }
+
+        let raw = RawEnvelope::deserialize(deserializer)?;
+        let event = Event::from_raw(raw.kind, raw.payload).map_err(D::Error::custom)?;
+
+        Ok(EventEnvelope {
+            id: raw.id,
+            at: raw.at,
+            event,
+        })
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractNegotiationEvent {
+    contract_negotiation_id: String,
+    counter_party_address: Option<String>,
+    counter_party_id: Option<String>,
+    protocol: Option<String>,
+    #[serde_as(deserialize_as = "OneOrMany<_, PreferMany>")]
+    #[serde(default)]
+    callback_addresses: Vec<CallbackAddress>,
+    #[serde(flatten)]
+    properties: Properties,
+}
+
+impl ContractNegotiationEvent {
+    pub fn contract_negotiation_id(&self) -> &str {
+        &self.contract_negotiation_id
+    }
+
+    pub fn counter_party_address(&self) -> Option<&String> {
+        self.counter_party_address.as_ref()
+    }
+
+    pub fn counter_party_id(&self) -> Option<&String> {
+        self.counter_party_id.as_ref()
+    }
+
+    pub fn protocol(&self) -> Option<&String> {
+        self.protocol.as_ref()
+    }
+
+    pub fn callback_addresses(&self) -> &[CallbackAddress] {
+        &self.callback_addresses
+    }
+
+    /// Payload fields not modelled above, e.g. `contractAgreement` on
+    /// finalized negotiations.
+    pub fn property<T>(&self, property: &str) -> Result<Option<T>, ConversionError>
+    where
+        T: FromValue,
+    {
+        self.properties.get(property)
+    }
+
+    pub fn properties(&self) -> &Properties {
+        &self.properties
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferProcessEvent {
+    transfer_process_id: String,
+    asset_id: Option<String>,
+    contract_id: Option<String>,
+    #[serde(rename = "type")]
+    kind: Option<TransferProcessKind>,
+    #[serde_as(deserialize_as = "OneOrMany<_, PreferMany>")]
+    #[serde(default)]
+    callback_addresses: Vec<CallbackAddress>,
+    #[serde(flatten)]
+    properties: Properties,
+}
+
+impl TransferProcessEvent {
+    pub fn transfer_process_id(&self) -> &str {
+        &self.transfer_process_id
+    }
+
+    pub fn asset_id(&self) -> Option<&String> {
+        self.asset_id.as_ref()
+    }
+
+    pub fn contract_id(&self) -> Option<&String> {
+        self.contract_id.as_ref()
+    }
+
+    pub fn kind(&self) -> Option<&TransferProcessKind> {
+        self.kind.as_ref()
+    }
+
+    pub fn callback_addresses(&self) -> &[CallbackAddress] {
+        &self.callback_addresses
+    }
+
+    /// Payload fields not modelled above, e.g. the `dataAddress` of a started
+    /// transfer.
+    pub fn property<T>(&self, property: &str) -> Result<Option<T>, ConversionError>
+    where
+        T: FromValue,
+    {
+        self.properties.get(property)
+    }
+
+    pub fn properties(&self) -> &Properties {
+        &self.properties
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{Event, EventEnvelope};
+
+    #[test]
+    fn should_deserialize_contract_negotiation_finalized() {
+        let envelope = serde_json::from_value::<EventEnvelope>(json!({
+            "id": "event-id",
+            "at": 1700000000000i64,
+            "type": "ContractNegotiationFinalized",
+            "payload": {
+                "contractNegotiationId": "negotiation-id",
+                "counterPartyAddress": "http://provider/protocol",
+                "counterPartyId": "provider",
+                "protocol": "dataspace-protocol-http",
+                "callbackAddresses": [],
+                "contractAgreement": { "id": "agreement-id" }
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(envelope.id(), "event-id");
+        assert_eq!(envelope.event().name(), "contract.negotiation.finalized");
+
+        match envelope.event() {
+            Event::ContractNegotiationFinalized(event) => {
+                assert_eq!(event.contract_negotiation_id(), "negotiation-id");
+                assert_eq!(event.counter_party_id().unwrap(), "provider");
+                assert!(event.properties().contains("contractAgreement"));
+            }
+            other => panic!("Unexpected event {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_deserialize_event_names() {
+        let envelope = serde_json::from_value::<EventEnvelope>(json!({
+            "id": "event-id",
+            "at": 1700000000000i64,
+            "type": "transfer.process.started",
+            "payload": {
+                "transferProcessId": "transfer-id",
+                "assetId": "asset-id",
+                "type": "CONSUMER"
+            }
+        }))
+        .unwrap();
+
+        match envelope.event() {
+            Event::TransferProcessStarted(event) => {
+                assert_eq!(event.transfer_process_id(), "transfer-id");
+                assert_eq!(event.asset_id().unwrap(), "asset-id");
+            }
+            other => panic!("Unexpected event {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_keep_unknown_events() {
+        let envelope = serde_json::from_value::<EventEnvelope>(json!({
+            "id": "event-id",
+            "at": 1700000000000i64,
+            "type": "AssetCreated",
+            "payload": { "assetId": "asset-id" }
+        }))
+        .unwrap();
+
+        assert_eq!(envelope.event().name(), "asset.created");
+        assert!(matches!(envelope.event(), Event::Other { kind, .. } if kind == "AssetCreated"));
+    }
+}
@@ -1,45 +1,69 @@
-use std::{future::Future, sync::Arc};
+mod interceptor;
+mod oauth2;
+mod retry;
 
-use reqwest::{Client, RequestBuilder, Response};
+use std::{future::Future, sync::Arc, time::Duration};
+
+#[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+use reqwest::{Certificate, Identity};
+use reqwest::{Client, ClientBuilder, Method, Proxy, RequestBuilder, Response, StatusCode};
 use serde::{de::DeserializeOwned, Serialize};
 
 use crate::{
     api::{
-        assets::AssetApi, catalog::CatalogApi, contract_agreement::ContractAgreementApi,
-        contract_definitions::ContractDefinitionApi, contract_negotiations::ContractNegotiationApi,
-        dataplanes::DataPlaneApi, edrs::EdrApi, policies::PolicyApi,
+        assets::AssetApi, catalog::CatalogApi, consume::Consume,
+        contract_agreement::ContractAgreementApi, contract_definitions::ContractDefinitionApi,
+        contract_negotiations::ContractNegotiationApi, dataplanes::DataPlaneApi, edrs::EdrApi,
+        policies::PolicyApi, public::PublicApi, secrets::SecretsApi,
         transfer_process::TransferProcessApi,
     },
     error::{
-        BuilderError, ManagementApiError, ManagementApiErrorDetail, ManagementApiErrorDetailKind,
+        BuilderError, DecodeError, ManagementApiError, ManagementApiErrorDetail,
+        ManagementApiErrorDetailKind,
     },
+    types::{catalog::Dataset, data_address::DataAddress, policy::Policy},
     EdcResult, Error,
 };
 
+pub use interceptor::Interceptor;
+pub use oauth2::{OAuth2ClientCredentials, OAuth2ClientCredentialsBuilder};
+pub use retry::{RetryPolicy, RetryPolicyBuilder};
+
+use self::oauth2::TokenCache;
+
 #[derive(Clone)]
 pub struct EdcConnectorClient(Arc<EdcConnectorClientInternal>);
 
 pub(crate) struct EdcConnectorClientInternal {
-    client: Client,
+    pub(crate) client: Client,
     pub(crate) management_url: String,
     pub(crate) auth: Auth,
+    token_cache: TokenCache,
+    interceptors: Vec<Arc<dyn Interceptor>>,
+    retry_policy: RetryPolicy,
 }
 
 impl EdcConnectorClientInternal {
-    pub(crate) fn new(client: Client, management_url: String, auth: Auth) -> Self {
+    pub(crate) fn new(
+        client: Client,
+        management_url: String,
+        auth: Auth,
+        interceptors: Vec<Arc<dyn Interceptor>>,
+        retry_policy: RetryPolicy,
+    ) -> Self {
         Self {
             client,
             management_url,
             auth,
+            token_cache: TokenCache::default(),
+            interceptors,
+            retry_policy,
         }
     }
 
     pub(crate) async fn get<R: DeserializeOwned>(&self, path: impl AsRef<str>) -> EdcResult<R> {
         let response = self
-            .client
-            .get(path.as_ref())
-            .authenticated(&self.auth)
-            .send()
+            .send(Method::GET, path.as_ref(), false, |request| request)
             .await?;
 
         self.handle_response(response, as_json).await
@@ -47,11 +71,9 @@ impl EdcConnectorClientInternal {
 
     pub(crate) async fn put(&self, path: impl AsRef<str>, body: &impl Serialize) -> EdcResult<()> {
         let response = self
-            .client
-            .put(path.as_ref())
-            .json(body)
-            .authenticated(&self.auth)
-            .send()
+            .send(Method::PUT, path.as_ref(), false, |request| {
+                request.json(body)
+            })
             .await?;
 
         self.handle_response(response, empty).await
@@ -59,10 +81,7 @@ impl EdcConnectorClientInternal {
 
     pub(crate) async fn del(&self, path: impl AsRef<str>) -> EdcResult<()> {
         let response = self
-            .client
-            .delete(path.as_ref())
-            .authenticated(&self.auth)
-            .send()
+            .send(Method::DELETE, path.as_ref(), false, |request| request)
             .await?;
 
         self.handle_response(response, empty).await
@@ -73,7 +92,7 @@ impl EdcConnectorClientInternal {
         path: impl AsRef<str>,
         body: &I,
     ) -> EdcResult<R> {
-        self.internal_post(path, body, as_json).await
+        self.internal_post(path, body, false, as_json).await
     }
 
     pub(crate) async fn post_no_response<I: Serialize>(
@@ -81,13 +100,48 @@ impl EdcConnectorClientInternal {
         path: impl AsRef<str>,
         body: &I,
     ) -> EdcResult<()> {
-        self.internal_post(path, body, empty).await
+        self.internal_post(path, body, false, empty).await
+    }
+
+    /// Posts a read-only request, like a query or a catalog request, which is
+    /// safe to replay on transient failures.
+    pub(crate) async fn query<I: Serialize, R: DeserializeOwned>(
+        &self,
+        path: impl AsRef<str>,
+        body: &I,
+    ) -> EdcResult<R> {
+        self.internal_post(path, body, true, as_json).await
+    }
+
+    /// Posts an action that takes no body.
+    pub(crate) async fn post_empty<R: DeserializeOwned>(
+        &self,
+        path: impl AsRef<str>,
+    ) -> EdcResult<R> {
+        let response = self
+            .send(Method::POST, path.as_ref(), false, |request| request)
+            .await?;
+
+        self.handle_response(response, as_json).await
+    }
+
+    /// Like [`query`](Self::query), for read-only actions that take no body.
+    pub(crate) async fn query_empty<R: DeserializeOwned>(
+        &self,
+        path: impl AsRef<str>,
+    ) -> EdcResult<R> {
+        let response = self
+            .send(Method::POST, path.as_ref(), true, |request| request)
+            .await?;
+
+        self.handle_response(response, as_json).await
     }
 
     async fn internal_post<I, F, Fut, R>(
         &self,
         path: impl AsRef<str>,
         body: &I,
+        read_only: bool,
         handler: F,
     ) -> EdcResult<R>
     where
@@ -96,16 +150,97 @@ impl EdcConnectorClientInternal {
         Fut: Future<Output = EdcResult<R>>,
     {
         let response = self
-            .client
-            .post(path.as_ref())
-            .json(body)
-            .authenticated(&self.auth)
-            .send()
+            .send(Method::POST, path.as_ref(), read_only, |request| {
+                request.json(body)
+            })
             .await?;
 
         self.handle_response(response, handler).await
     }
 
+    /// Sends a request, retrying it according to the configured [`RetryPolicy`]
+    /// when it is safe to replay.
+    async fn send<F>(
+        &self,
+        method: Method,
+        path: &str,
+        read_only: bool,
+        body: F,
+    ) -> EdcResult<Response>
+    where
+        F: Fn(RequestBuilder) -> RequestBuilder,
+    {
+        let replayable = self.retry_policy.can_replay(&method, read_only);
+        let request = || body(self.client.request(method.clone(), path));
+        let mut attempt = 1;
+
+        loop {
+            let result = self.send_authenticated(&request).await;
+
+            let retry = replayable
+                && attempt < self.retry_policy.max_attempts()
+                && match &result {
+                    Ok(response) => self.retry_policy.should_retry_status(response.status()),
+                    Err(Error::Reqwest(err)) => self.retry_policy.should_retry_error(err),
+                    Err(_) => false,
+                };
+
+            if !retry {
+                return result;
+            }
+
+            tokio::time::sleep(self.retry_policy.backoff(attempt)).await;
+            attempt += 1;
+        }
+    }
+
+    /// Sends the request built by `request` with the configured authentication
+    /// and interceptors.
+    ///
+    /// With OAuth2 a `401` invalidates the cached token and the request is
+    /// rebuilt and sent once more with a freshly fetched one.
+    async fn send_authenticated<F>(&self, request: F) -> EdcResult<Response>
+    where
+        F: Fn() -> RequestBuilder,
+    {
+        let response = self.execute(request()).await?;
+
+        match &self.auth {
+            Auth::OAuth2ClientCredentials(_) if response.status() == StatusCode::UNAUTHORIZED => {
+                self.token_cache.invalidate().await;
+                self.execute(request()).await
+            }
+            _ => Ok(response),
+        }
+    }
+
+    async fn execute(&self, request: RequestBuilder) -> EdcResult<Response> {
+        let mut request = self.authenticate(request).await?.build()?;
+
+        for interceptor in &self.interceptors {
+            interceptor.on_request(&mut request).await?;
+        }
+
+        let mut response = self.client.execute(request).await?;
+
+        for interceptor in self.interceptors.iter().rev() {
+            interceptor.on_response(&mut response).await?;
+        }
+
+        Ok(response)
+    }
+
+    async fn authenticate(&self, request: RequestBuilder) -> EdcResult<RequestBuilder> {
+        match &self.auth {
+            Auth::NoAuth => Ok(request),
+            Auth::ApiToken(token) => Ok(request.header("X-Api-Key", token)),
+            Auth::OAuth2ClientCredentials(credentials) => {
+                let token = self.token_cache.token(&self.client, credentials).await?;
+                Ok(request.bearer_auth(token))
+            }
+        }
+    }
+
     async fn handle_response<F, Fut, R>(&self, response: Response, handler: F) -> EdcResult<R>
     where
         F: Fn(Response) -> Fut,
@@ -131,7 +266,9 @@ impl EdcConnectorClientInternal {
 }
 
 async fn as_json<R: DeserializeOwned>(response: Response) -> EdcResult<R> {
-    response.json().await.map(Ok)?
+    let body = response.text().await?;
+
+    serde_json::from_str(&body).map_err(|source| Error::Decode(DecodeError { body, source }))
 }
 
 async fn empty(_response: Response) -> EdcResult<()> {
@@ -139,11 +276,19 @@ async fn empty(_response: Response) -> EdcResult<()> {
 }
 
 impl EdcConnectorClient {
-    pub(crate) fn new(client: Client, management_url: String, auth: Auth) -> Self {
+    pub(crate) fn new(
+        client: Client,
+        management_url: String,
+        auth: Auth,
+        interceptors: Vec<Arc<dyn Interceptor>>,
+        retry_policy: RetryPolicy,
+    ) -> Self {
         Self(Arc::new(EdcConnectorClientInternal::new(
             client,
             management_url,
             auth,
+            interceptors,
+            retry_policy,
         )))
     }
 
@@ -186,23 +331,54 @@ impl EdcConnectorClient {
     pub fn edrs(&self) -> EdrApi<'_> {
         EdrApi::new(&self.0)
     }
+
+    /// Public API of the data plane referenced by an EDR data address, as
+    /// returned by `edrs().get_data_address`.
+    pub fn public_api(&self, data_address: &DataAddress) -> EdcResult<PublicApi<'_>> {
+        PublicApi::new(&self.0, data_address)
+    }
+
+    pub fn secrets(&self) -> SecretsApi<'_> {
+        SecretsApi::new(&self.0)
+    }
+
+    pub fn consume(&self, dataset: &Dataset, offer: &Policy) -> Consume<'_> {
+        Consume::new(&self.0, dataset, offer)
+    }
+
+    #[cfg(feature = "reconcile")]
+    pub fn reconcile<'a>(
+        &'a self,
+        manifest: &'a crate::reconcile::Manifest,
+    ) -> crate::reconcile::Reconcile<'a> {
+        crate::reconcile::Reconcile::new(&self.0, manifest)
+    }
 }
 
 #[derive(Clone)]
 pub enum Auth {
     NoAuth,
     ApiToken(String),
+    OAuth2ClientCredentials(OAuth2ClientCredentials),
 }
 
 impl Auth {
     pub fn api_token(token: impl Into<String>) -> Auth {
         Auth::ApiToken(token.into())
     }
+
+    pub fn oauth2_client_credentials(credentials: OAuth2ClientCredentials) -> Auth {
+        Auth::OAuth2ClientCredentials(credentials)
+    }
 }
 
 pub struct EdcClientConnectorBuilder {
     management_url: Option<String>,
     auth: Auth,
+    interceptors: Vec<Arc<dyn Interceptor>>,
+    retry_policy: RetryPolicy,
+    http_client: Option<Client>,
+    http_client_builder: ClientBuilder,
 }
 
 impl EdcClientConnectorBuilder {
@@ -216,12 +392,80 @@ impl EdcClientConnectorBuilder {
         self
     }
 
+    pub fn with_interceptor(mut self, interceptor: impl Interceptor + 'static) -> Self {
+        self.interceptors.push(Arc::new(interceptor));
+        self
+    }
+
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Uses the given `reqwest::Client` for all the requests.
+    ///
+    /// When set, the timeout, proxy and TLS options of this builder are
+    /// ignored and must be configured on the provided client instead.
+    pub fn with_http_client(mut self, client: Client) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.http_client_builder = self.http_client_builder.connect_timeout(timeout);
+        self
+    }
+
+    /// Timeout applied to the whole request, from connecting until the response
+    /// body has been read.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.http_client_builder = self.http_client_builder.timeout(timeout);
+        self
+    }
+
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.http_client_builder = self.http_client_builder.read_timeout(timeout);
+        self
+    }
+
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.http_client_builder = self.http_client_builder.proxy(proxy);
+        self
+    }
+
+    pub fn no_proxy(mut self) -> Self {
+        self.http_client_builder = self.http_client_builder.no_proxy();
+        self
+    }
+
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    pub fn add_root_certificate(mut self, certificate: Certificate) -> Self {
+        self.http_client_builder = self.http_client_builder.add_root_certificate(certificate);
+        self
+    }
+
+    /// Client certificate used for mTLS.
+    #[cfg(any(feature = "native-tls", feature = "rustls-tls"))]
+    pub fn identity(mut self, identity: Identity) -> Self {
+        self.http_client_builder = self.http_client_builder.identity(identity);
+        self
+    }
+
     pub fn build(self) -> Result<EdcConnectorClient, BuilderError> {
         let url = self
             .management_url
             .ok_or_else(|| BuilderError::missing_property("management_url"))?;
-        let client = Client::new();
-        Ok(EdcConnectorClient::new(client, url, self.auth))
+        let client = match self.http_client {
+            Some(client) => client,
+            None => self.http_client_builder.build()?,
+        };
+        Ok(EdcConnectorClient::new(
+            client,
+            url,
+            self.auth,
+            self.interceptors,
+            self.retry_policy,
+        ))
     }
 }
 
@@ -230,19 +474,10 @@ impl Default for EdcClientConnectorBuilder {
         Self {
             management_url: Default::default(),
             auth: Auth::NoAuth,
-        }
-    }
-}
-
-trait BuilderExt {
-    fn authenticated(self, auth: &Auth) -> Self;
-}
-
-impl BuilderExt for RequestBuilder {
-    fn authenticated(self, auth: &Auth) -> Self {
-        match auth {
-            Auth::NoAuth => self,
-            Auth::ApiToken(token) => self.header("X-Api-Key", token),
+            interceptors: vec![],
+            retry_policy: RetryPolicy::none(),
+            http_client: None,
+            http_client_builder: Client::builder(),
         }
     }
 }
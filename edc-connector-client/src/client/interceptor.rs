@@ -0,0 +1,43 @@
+use reqwest::{Request, Response};
+
+use crate::EdcResult;
+
+/// Hook into every request sent to the management API.
+///
+/// Interceptors are registered with `EdcClientConnectorBuilder::with_interceptor`
+/// and see requests after authentication has been applied, so they can also
+/// be used for signing. `on_request` runs in registration order, `on_response`
+/// in reverse registration order.
+///
+/// ```rust,no_run
+/// use edc_connector_client::{EdcConnectorClient, EdcResult, Interceptor};
+/// use reqwest::Request;
+///
+/// struct CorrelationId;
+///
+/// #[async_trait::async_trait]
+/// impl Interceptor for CorrelationId {
+///     async fn on_request(&self, request: &mut Request) -> EdcResult<()> {
+///         request
+///             .headers_mut()
+///             .insert("X-Correlation-Id", "my-correlation-id".parse().unwrap());
+///         Ok(())
+///     }
+/// }
+///
+/// let client = EdcConnectorClient::builder()
+///     .management_url("http://myedc")
+///     .with_interceptor(CorrelationId)
+///     .build()
+///     .unwrap();
+/// ```
+#[async_trait::async_trait]
+pub trait Interceptor: Send + Sync {
+    async fn on_request(&self, _request: &mut Request) -> EdcResult<()> {
+        Ok(())
+    }
+
+    async fn on_response(&self, _response: &mut Response) -> EdcResult<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,209 @@
+use std::time::Duration;
+
+use reqwest::Client;
+use serde::Deserialize;
+use tokio::{sync::Mutex, time::Instant};
+
+use crate::{
+    error::{BuilderError, DecodeError, TokenEndpointError},
+    EdcResult, Error,
+};
+
+const DEFAULT_REFRESH_SKEW: Duration = Duration::from_secs(30);
+
+#[derive(Clone)]
+pub struct OAuth2ClientCredentials {
+    token_url: String,
+    client_id: String,
+    client_secret: String,
+    scope: Option<String>,
+    audience: Option<String>,
+    refresh_skew: Duration,
+}
+
+impl OAuth2ClientCredentials {
+    pub fn builder() -> OAuth2ClientCredentialsBuilder {
+        OAuth2ClientCredentialsBuilder::default()
+    }
+
+    pub fn token_url(&self) -> &str {
+        &self.token_url
+    }
+
+    pub fn client_id(&self) -> &str {
+        &self.client_id
+    }
+
+    pub fn scope(&self) -> Option<&String> {
+        self.scope.as_ref()
+    }
+
+    pub fn audience(&self) -> Option<&String> {
+        self.audience.as_ref()
+    }
+
+    pub fn refresh_skew(&self) -> Duration {
+        self.refresh_skew
+    }
+
+    fn form(&self) -> Vec<(&str, &str)> {
+        let mut form = vec![
+            ("grant_type", "client_credentials"),
+            ("client_id", self.client_id.as_str()),
+            ("client_secret", self.client_secret.as_str()),
+        ];
+
+        if let Some(scope) = &self.scope {
+            form.push(("scope", scope));
+        }
+
+        if let Some(audience) = &self.audience {
+            form.push(("audience", audience));
+        }
+
+        form
+    }
+}
+
+#[derive(Default)]
+pub struct OAuth2ClientCredentialsBuilder {
+    token_url: Option<String>,
+    client_id: Option<String>,
+    client_secret: Option<String>,
+    scope: Option<String>,
+    audience: Option<String>,
+    refresh_skew: Option<Duration>,
+}
+
+impl OAuth2ClientCredentialsBuilder {
+    pub fn token_url(mut self, token_url: impl Into<String>) -> Self {
+        self.token_url = Some(token_url.into());
+        self
+    }
+
+    pub fn client_id(mut self, client_id: impl Into<String>) -> Self {
+        self.client_id = Some(client_id.into());
+        self
+    }
+
+    pub fn client_secret(mut self, client_secret: impl Into<String>) -> Self {
+        self.client_secret = Some(client_secret.into());
+        self
+    }
+
+    pub fn scope(mut self, scope: impl Into<String>) -> Self {
+        self.scope = Some(scope.into());
+        self
+    }
+
+    pub fn audience(mut self, audience: impl Into<String>) -> Self {
+        self.audience = Some(audience.into());
+        self
+    }
+
+    /// How long before the advertised expiry the token is considered stale
+    /// and fetched again. Defaults to 30 seconds.
+    pub fn refresh_skew(mut self, refresh_skew: Duration) -> Self {
+        self.refresh_skew = Some(refresh_skew);
+        self
+    }
+
+    pub fn build(self) -> Result<OAuth2ClientCredentials, BuilderError> {
+        Ok(OAuth2ClientCredentials {
+            token_url: self
+                .token_url
+                .ok_or_else(|| BuilderError::missing_property("token_url"))?,
+            client_id: self
+                .client_id
+                .ok_or_else(|| BuilderError::missing_property("client_id"))?,
+            client_secret: self
+                .client_secret
+                .ok_or_else(|| BuilderError::missing_property("client_secret"))?,
+            scope: self.scope,
+            audience: self.audience,
+            refresh_skew: self.refresh_skew.unwrap_or(DEFAULT_REFRESH_SKEW),
+        })
+    }
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: Option<u64>,
+}
+
+struct AccessToken {
+    value: String,
+    expires_at: Option<Instant>,
+}
+
+impl AccessToken {
+    fn is_fresh(&self) -> bool {
+        self.expires_at
+            .map(|expires_at| Instant::now() < expires_at)
+            .unwrap_or(true)
+    }
+}
+
+/// Caches the access token obtained with the client credentials grant.
+///
+/// The lock is held while fetching, so concurrent requests wait for a single
+/// round trip to the token endpoint instead of racing each other.
+#[derive(Default)]
+pub(crate) struct TokenCache(Mutex<Option<AccessToken>>);
+
+impl TokenCache {
+    pub(crate) async fn token(
+        &self,
+        client: &Client,
+        credentials: &OAuth2ClientCredentials,
+    ) -> EdcResult<String> {
+        let mut cached = self.0.lock().await;
+
+        if let Some(token) = cached.as_ref().filter(|token| token.is_fresh()) {
+            return Ok(token.value.clone());
+        }
+
+        let token = fetch_token(client, credentials).await?;
+        let value = token.value.clone();
+        *cached = Some(token);
+
+        Ok(value)
+    }
+
+    pub(crate) async fn invalidate(&self) {
+        self.0.lock().await.take();
+    }
+}
+
+async fn fetch_token(
+    client: &Client,
+    credentials: &OAuth2ClientCredentials,
+) -> EdcResult<AccessToken> {
+    let response = client
+        .post(&credentials.token_url)
+        .form(&credentials.form())
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        let status_code = response.status();
+        let body = response.text().await?;
+        return Err(Error::TokenEndpoint(TokenEndpointError {
+            status_code,
+            body,
+        }));
+    }
+
+    let body = response.text().await?;
+    let token = serde_json::from_str::<TokenResponse>(&body)
+        .map_err(|source| Error::Decode(DecodeError { body, source }))?;
+
+    Ok(AccessToken {
+        value: token.access_token,
+        expires_at: token.expires_in.map(|expires_in| {
+            Instant::now()
+                + Duration::from_secs(expires_in).saturating_sub(credentials.refresh_skew)
+        }),
+    })
+}
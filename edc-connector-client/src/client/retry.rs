@@ -0,0 +1,267 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+use reqwest::{Method, StatusCode};
+
+/// Policy for retrying management API calls that failed with a transient error.
+///
+/// Only requests that are safe to replay are retried: the ones whose method is
+/// listed in [`RetryPolicyBuilder::retryable_methods`] (`GET`, `PUT`, `DELETE`
+/// by default) and the read-only `POST`s used for queries and catalog requests.
+/// State changing `POST`s, like initiating a negotiation, are never replayed
+/// unless `POST` is explicitly added to the retryable methods.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    multiplier: f64,
+    jitter: bool,
+    retryable_status_codes: Vec<StatusCode>,
+    retryable_methods: Vec<Method>,
+    retry_connection_errors: bool,
+}
+
+impl RetryPolicy {
+    pub fn builder() -> RetryPolicyBuilder {
+        RetryPolicyBuilder(RetryPolicy::default())
+    }
+
+    /// A policy that sends every request exactly once.
+    pub fn none() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 1,
+            ..RetryPolicy::default()
+        }
+    }
+
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    pub fn initial_backoff(&self) -> Duration {
+        self.initial_backoff
+    }
+
+    pub fn max_backoff(&self) -> Duration {
+        self.max_backoff
+    }
+
+    pub fn multiplier(&self) -> f64 {
+        self.multiplier
+    }
+
+    pub fn jitter(&self) -> bool {
+        self.jitter
+    }
+
+    pub fn retryable_status_codes(&self) -> &[StatusCode] {
+        &self.retryable_status_codes
+    }
+
+    pub fn retryable_methods(&self) -> &[Method] {
+        &self.retryable_methods
+    }
+
+    pub fn retry_connection_errors(&self) -> bool {
+        self.retry_connection_errors
+    }
+
+    pub(crate) fn can_replay(&self, method: &Method, read_only: bool) -> bool {
+        read_only || self.retryable_methods.contains(method)
+    }
+
+    pub(crate) fn should_retry_status(&self, status: StatusCode) -> bool {
+        self.retryable_status_codes.contains(&status)
+    }
+
+    pub(crate) fn should_retry_error(&self, error: &reqwest::Error) -> bool {
+        self.retry_connection_errors
+            && (error.is_connect() || error.is_timeout() || error.is_request())
+    }
+
+    /// Delay before the given retry, `1` being the first retry.
+    pub(crate) fn backoff(&self, retry: u32) -> Duration {
+        let exponent = retry.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let factor = self.multiplier.powi(exponent).min(f64::MAX);
+        let secs =
+            (self.initial_backoff.as_secs_f64() * factor).min(self.max_backoff.as_secs_f64());
+        let delay = Duration::try_from_secs_f64(secs).unwrap_or(self.max_backoff);
+
+        if self.jitter {
+            delay / 2 + delay.mul_f64(random_fraction() / 2.0)
+        } else {
+            delay
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(5),
+            multiplier: 2.0,
+            jitter: true,
+            retryable_status_codes: vec![
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+            retryable_methods: vec![Method::GET, Method::PUT, Method::DELETE],
+            retry_connection_errors: true,
+        }
+    }
+}
+
+pub struct RetryPolicyBuilder(RetryPolicy);
+
+impl RetryPolicyBuilder {
+    /// Total number of attempts, including the first one.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.0.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    pub fn initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.0.initial_backoff = initial_backoff;
+        self
+    }
+
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.0.max_backoff = max_backoff;
+        self
+    }
+
+    /// Factor applied to the backoff after each retry. Values below `1.0`,
+    /// infinite or NaN fall back to `1.0`, a constant backoff.
+    pub fn multiplier(mut self, multiplier: f64) -> Self {
+        self.0.multiplier = if multiplier.is_finite() {
+            multiplier.max(1.0)
+        } else {
+            1.0
+        };
+        self
+    }
+
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.0.jitter = jitter;
+        self
+    }
+
+    pub fn retryable_status_codes(mut self, status_codes: Vec<StatusCode>) -> Self {
+        self.0.retryable_status_codes = status_codes;
+        self
+    }
+
+    pub fn retryable_status_code(mut self, status_code: StatusCode) -> Self {
+        self.0.retryable_status_codes.push(status_code);
+        self
+    }
+
+    pub fn retryable_methods(mut self, methods: Vec<Method>) -> Self {
+        self.0.retryable_methods = methods;
+        self
+    }
+
+    pub fn retryable_method(mut self, method: Method) -> Self {
+        self.0.retryable_methods.push(method);
+        self
+    }
+
+    pub fn retry_connection_errors(mut self, retry_connection_errors: bool) -> Self {
+        self.0.retry_connection_errors = retry_connection_errors;
+        self
+    }
+
+    pub fn build(self) -> RetryPolicy {
+        self.0
+    }
+}
+
+fn random_fraction() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u64(
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or_default(),
+    );
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use reqwest::Method;
+
+    use super::RetryPolicy;
+
+    #[test]
+    fn should_grow_backoff_exponentially_up_to_max() {
+        let policy = RetryPolicy::builder()
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_millis(350))
+            .jitter(false)
+            .build();
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(350));
+    }
+
+    #[test]
+    fn should_cap_backoff_without_overflowing() {
+        let policy = RetryPolicy::builder()
+            .max_attempts(25)
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_secs(5))
+            .multiplier(10.0)
+            .jitter(false)
+            .build();
+
+        assert_eq!(policy.backoff(22), Duration::from_secs(5));
+        assert_eq!(policy.backoff(u32::MAX), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn should_fall_back_to_a_constant_backoff_on_invalid_multiplier() {
+        for multiplier in [-2.0, 0.5, f64::NAN, f64::INFINITY] {
+            let policy = RetryPolicy::builder()
+                .initial_backoff(Duration::from_millis(100))
+                .multiplier(multiplier)
+                .jitter(false)
+                .build();
+
+            assert_eq!(policy.multiplier(), 1.0);
+            assert_eq!(policy.backoff(3), Duration::from_millis(100));
+        }
+    }
+
+    #[test]
+    fn should_keep_jittered_backoff_within_bounds() {
+        let policy = RetryPolicy::builder()
+            .initial_backoff(Duration::from_millis(100))
+            .build();
+
+        for _ in 0..100 {
+            let backoff = policy.backoff(1);
+            assert!(backoff >= Duration::from_millis(50));
+            assert!(backoff <= Duration::from_millis(100));
+        }
+    }
+
+    #[test]
+    fn should_not_replay_post_unless_read_only() {
+        let policy = RetryPolicy::default();
+
+        assert!(policy.can_replay(&Method::GET, false));
+        assert!(policy.can_replay(&Method::POST, true));
+        assert!(!policy.can_replay(&Method::POST, false));
+    }
+}
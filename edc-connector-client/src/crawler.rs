@@ -0,0 +1,368 @@
+//! Crawler requesting the catalogs of many participants into a local index.
+//!
+//! Enabled with the `crawler` feature.
+//!
+//! The [`Crawler`] requests the catalogs of its participants concurrently,
+//! paging through each of them, and keeps the datasets in a [`CatalogIndex`]
+//! by participant and dataset id. Requests are spread over time with a rate
+//! limit shared by all the participants. Refreshing only requests the catalogs
+//! that failed or are older than the maximum age, and reports the datasets
+//! added, updated and removed since the previous crawl.
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//!
+//! use edc_connector_client::{
+//!     crawler::{CatalogIndex, Crawler, Participant, Search},
+//!     EdcConnectorClient,
+//! };
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let client = EdcConnectorClient::builder()
+//!         .management_url("http://myedc")
+//!         .build()?;
+//!
+//!     let crawler = Crawler::builder(client)
+//!         .participant(Participant::new("provider-a", "http://provider-a/protocol"))
+//!         .participant(Participant::new("provider-b", "http://provider-b/protocol"))
+//!         .concurrency(8)
+//!         .rate_limit(10, Duration::from_secs(1))
+//!         .timeout(Duration::from_secs(30))
+//!         .max_age(Duration::from_secs(600))
+//!         .build();
+//!
+//!     let mut index = CatalogIndex::new();
+//!     let report = crawler.refresh(&mut index).await;
+//!
+//!     for failure in report.failures() {
+//!         println!("{}: {}", failure.participant_id(), failure.error().unwrap());
+//!     }
+//!
+//!     for found in index.search(&Search::new().property("contenttype", "application/json")) {
+//!         println!("{} from {}", found.dataset().id(), found.participant_id());
+//!     }
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod index;
+mod search;
+
+use std::{future::Future, sync::Mutex, time::Duration};
+
+use futures::{stream, StreamExt};
+use tokio::time::Instant;
+
+use crate::{
+    types::{
+        catalog::{Catalog, CatalogRequest, Dataset, DatasetRequest},
+        query::Query,
+    },
+    CatalogService, EdcConnectorClient, EdcResult, Error, WorkflowError,
+};
+
+pub use self::{
+    index::{CatalogIndex, Changes, IndexedDataset, ParticipantCatalog},
+    search::Search,
+};
+
+/// A connector whose catalog is crawled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Participant {
+    id: String,
+    address: String,
+}
+
+impl Participant {
+    /// A participant with its id and its dataspace protocol address, e.g.
+    /// `http://provider:19194/protocol`.
+    pub fn new(id: impl Into<String>, address: impl Into<String>) -> Participant {
+        Participant {
+            id: id.into(),
+            address: address.into(),
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+}
+
+pub struct Crawler<C = EdcConnectorClient> {
+    catalog: C,
+    participants: Vec<Participant>,
+    concurrency: usize,
+    page_size: u32,
+    max_age: Duration,
+    timeout: Duration,
+    rate_limiter: RateLimiter,
+}
+
+impl<C: CatalogService> Crawler<C> {
+    /// A crawler requesting the catalogs with `catalog`, usually an
+    /// [`EdcConnectorClient`].
+    pub fn builder(catalog: C) -> CrawlerBuilder<C> {
+        CrawlerBuilder {
+            catalog,
+            participants: vec![],
+            concurrency: 4,
+            page_size: 50,
+            max_age: Duration::from_secs(3600),
+            timeout: Duration::from_secs(60),
+            interval: None,
+        }
+    }
+
+    pub fn participants(&self) -> &[Participant] {
+        &self.participants
+    }
+
+    /// Crawls the participants missing from the index, failed at their last
+    /// crawl or crawled longer than the maximum age ago.
+    pub async fn refresh(&self, index: &mut CatalogIndex) -> CrawlReport {
+        let stale = self
+            .participants
+            .iter()
+            .filter(|participant| index.is_stale(participant.id(), self.max_age))
+            .collect();
+
+        self.crawl_participants(stale, index).await
+    }
+
+    /// Crawls all the participants.
+    pub async fn crawl(&self, index: &mut CatalogIndex) -> CrawlReport {
+        self.crawl_participants(self.participants.iter().collect(), index)
+            .await
+    }
+
+    async fn crawl_participants(
+        &self,
+        participants: Vec<&Participant>,
+        index: &mut CatalogIndex,
+    ) -> CrawlReport {
+        let mut results = stream::iter(participants.into_iter().enumerate())
+            .map(|(position, participant)| async move {
+                (position, participant, self.fetch(participant).await)
+            })
+            .buffer_unordered(self.concurrency)
+            .collect::<Vec<_>>()
+            .await;
+
+        results.sort_by_key(|(position, _, _)| *position);
+
+        let participants = results
+            .into_iter()
+            .map(|(_, participant, result)| ParticipantReport {
+                participant_id: participant.id().to_string(),
+                result: match result {
+                    Ok(datasets) => Ok(index.update(participant.id(), datasets)),
+                    Err(err) => {
+                        index.record_failure(participant.id(), &err);
+                        Err(err)
+                    }
+                },
+            })
+            .collect();
+
+        CrawlReport { participants }
+    }
+
+    async fn fetch(&self, participant: &Participant) -> EdcResult<Vec<Dataset>> {
+        let request = CatalogRequest::builder()
+            .counter_party_address(participant.address())
+            .counter_party_id(participant.id())
+            .query_spec(Query::builder().limit(self.page_size).build())
+            .build()?;
+
+        RateLimited {
+            catalog: &self.catalog,
+            rate_limiter: &self.rate_limiter,
+            participant,
+            timeout: self.timeout,
+        }
+        .request_all_datasets(&request)
+        .await
+    }
+}
+
+pub struct CrawlerBuilder<C> {
+    catalog: C,
+    participants: Vec<Participant>,
+    concurrency: usize,
+    page_size: u32,
+    max_age: Duration,
+    timeout: Duration,
+    interval: Option<Duration>,
+}
+
+impl<C: CatalogService> CrawlerBuilder<C> {
+    pub fn participant(mut self, participant: Participant) -> Self {
+        self.participants.push(participant);
+        self
+    }
+
+    pub fn participants(mut self, participants: impl IntoIterator<Item = Participant>) -> Self {
+        self.participants.extend(participants);
+        self
+    }
+
+    /// Number of catalogs requested at the same time. Defaults to 4.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Number of entries requested per catalog page. Defaults to 50.
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Age after which a catalog is requested again when refreshing.
+    /// Defaults to one hour.
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = max_age;
+        self
+    }
+
+    /// Maximum time to wait for each catalog request of a participant before
+    /// reporting it as failed. The time spent waiting for the rate limit is
+    /// not included. Defaults to one minute.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Spreads the catalog requests, pages included, so that at most
+    /// `requests` are sent every `per`, across all the participants.
+    pub fn rate_limit(mut self, requests: u32, per: Duration) -> Self {
+        self.interval = Some(per / requests.max(1));
+        self
+    }
+
+    pub fn build(self) -> Crawler<C> {
+        Crawler {
+            catalog: self.catalog,
+            participants: self.participants,
+            concurrency: self.concurrency,
+            page_size: self.page_size,
+            max_age: self.max_age,
+            timeout: self.timeout,
+            rate_limiter: RateLimiter {
+                interval: self.interval,
+                next: Mutex::new(None),
+            },
+        }
+    }
+}
+
+/// Outcome of a crawl, with one report per crawled participant in the order
+/// of the participants of the crawler.
+#[derive(Debug)]
+pub struct CrawlReport {
+    participants: Vec<ParticipantReport>,
+}
+
+impl CrawlReport {
+    pub fn participants(&self) -> &[ParticipantReport] {
+        &self.participants
+    }
+
+    pub fn failures(&self) -> impl Iterator<Item = &ParticipantReport> {
+        self.participants
+            .iter()
+            .filter(|report| report.result.is_err())
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.failures().next().is_none()
+    }
+}
+
+#[derive(Debug)]
+pub struct ParticipantReport {
+    participant_id: String,
+    result: Result<Changes, Error>,
+}
+
+impl ParticipantReport {
+    pub fn participant_id(&self) -> &str {
+        &self.participant_id
+    }
+
+    /// Changes to the datasets of the participant, if its catalog was
+    /// crawled.
+    pub fn changes(&self) -> Option<&Changes> {
+        self.result.as_ref().ok()
+    }
+
+    /// Why the catalog could not be crawled. The datasets of the previous
+    /// crawl are kept in the index.
+    pub fn error(&self) -> Option<&Error> {
+        self.result.as_ref().err()
+    }
+}
+
+/// Hands out evenly spaced slots for requests.
+struct RateLimiter {
+    interval: Option<Duration>,
+    next: Mutex<Option<Instant>>,
+}
+
+impl RateLimiter {
+    async fn acquire(&self) {
+        let Some(interval) = self.interval else {
+            return;
+        };
+
+        let slot = {
+            let mut next = self.next.lock().unwrap_or_else(|err| err.into_inner());
+            let now = Instant::now();
+            let slot = next.map_or(now, |next| next.max(now));
+            *next = Some(slot + interval);
+            slot
+        };
+
+        tokio::time::sleep_until(slot).await;
+    }
+}
+
+/// Catalog service of a participant waiting for the rate limiter before
+/// every request, and timing out the request itself.
+struct RateLimited<'a, C> {
+    catalog: &'a C,
+    rate_limiter: &'a RateLimiter,
+    participant: &'a Participant,
+    timeout: Duration,
+}
+
+impl<C> RateLimited<'_, C> {
+    async fn send<T>(&self, request: impl Future<Output = EdcResult<T>>) -> EdcResult<T> {
+        self.rate_limiter.acquire().await;
+        tokio::time::timeout(self.timeout, request)
+            .await
+            .map_err(|_| {
+                Error::Workflow(WorkflowError::Timeout(format!(
+                    "the catalog of {}",
+                    self.participant.id()
+                )))
+            })?
+    }
+}
+
+#[async_trait::async_trait]
+impl<C: CatalogService> CatalogService for RateLimited<'_, C> {
+    async fn request_catalog(&self, request: &CatalogRequest) -> EdcResult<Catalog> {
+        self.send(self.catalog.request_catalog(request)).await
+    }
+
+    async fn request_dataset(&self, request: &DatasetRequest) -> EdcResult<Dataset> {
+        self.send(self.catalog.request_dataset(request)).await
+    }
+}
@@ -0,0 +1,200 @@
+use std::{
+    collections::BTreeMap,
+    time::{Duration, SystemTime},
+};
+
+use crate::{types::catalog::Dataset, Error};
+
+use super::Search;
+
+/// Datasets crawled from the catalogs of the participants, by participant
+/// and dataset id.
+#[derive(Debug, Default)]
+pub struct CatalogIndex {
+    participants: BTreeMap<String, ParticipantCatalog>,
+}
+
+impl CatalogIndex {
+    pub fn new() -> CatalogIndex {
+        CatalogIndex::default()
+    }
+
+    pub fn participant(&self, participant_id: &str) -> Option<&ParticipantCatalog> {
+        self.participants.get(participant_id)
+    }
+
+    pub fn participants(&self) -> impl Iterator<Item = (&str, &ParticipantCatalog)> {
+        self.participants
+            .iter()
+            .map(|(id, catalog)| (id.as_str(), catalog))
+    }
+
+    pub fn dataset(&self, participant_id: &str, dataset_id: &str) -> Option<&Dataset> {
+        self.participant(participant_id)
+            .and_then(|catalog| catalog.dataset(dataset_id))
+    }
+
+    /// All the datasets, ordered by participant and dataset id.
+    pub fn datasets(&self) -> impl Iterator<Item = IndexedDataset<'_>> {
+        self.participants
+            .iter()
+            .flat_map(|(participant_id, catalog)| {
+                catalog.datasets.values().map(|dataset| IndexedDataset {
+                    participant_id,
+                    dataset,
+                })
+            })
+    }
+
+    pub fn search(&self, search: &Search) -> Vec<IndexedDataset<'_>> {
+        self.datasets()
+            .filter(|found| search.matches(found.participant_id, found.dataset))
+            .collect()
+    }
+
+    /// Number of datasets.
+    pub fn len(&self) -> usize {
+        self.participants
+            .values()
+            .map(|catalog| catalog.datasets.len())
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes a participant and its datasets, e.g. when leaving the
+    /// dataspace.
+    pub fn remove(&mut self, participant_id: &str) -> Option<ParticipantCatalog> {
+        self.participants.remove(participant_id)
+    }
+
+    pub(crate) fn is_stale(&self, participant_id: &str, max_age: Duration) -> bool {
+        match self.participants.get(participant_id) {
+            Some(catalog) if catalog.last_error.is_none() => catalog
+                .crawled_at
+                .and_then(|crawled_at| crawled_at.elapsed().ok())
+                .map(|age| age >= max_age)
+                .unwrap_or(true),
+            _ => true,
+        }
+    }
+
+    /// Replaces the datasets of a participant with the crawled ones.
+    pub(crate) fn update(&mut self, participant_id: &str, datasets: Vec<Dataset>) -> Changes {
+        let catalog = self
+            .participants
+            .entry(participant_id.to_string())
+            .or_default();
+
+        let datasets = datasets
+            .into_iter()
+            .map(|dataset| (dataset.id().to_string(), dataset))
+            .collect::<BTreeMap<_, _>>();
+
+        let mut changes = Changes::default();
+        for (id, dataset) in &datasets {
+            match catalog.datasets.get(id) {
+                None => changes.added.push(id.clone()),
+                Some(current) if !same(current, dataset) => changes.updated.push(id.clone()),
+                Some(_) => {}
+            }
+        }
+        changes.removed = catalog
+            .datasets
+            .keys()
+            .filter(|id| !datasets.contains_key(*id))
+            .cloned()
+            .collect();
+
+        catalog.datasets = datasets;
+        catalog.crawled_at = Some(SystemTime::now());
+        catalog.last_error = None;
+
+        changes
+    }
+
+    /// Flags a participant for the next refresh, keeping its datasets.
+    pub(crate) fn record_failure(&mut self, participant_id: &str, err: &Error) {
+        self.participants
+            .entry(participant_id.to_string())
+            .or_default()
+            .last_error = Some(err.to_string());
+    }
+}
+
+fn same(left: &Dataset, right: &Dataset) -> bool {
+    serde_json::to_value(left).ok() == serde_json::to_value(right).ok()
+}
+
+/// The datasets of a participant and the state of its last crawl.
+#[derive(Debug, Default)]
+pub struct ParticipantCatalog {
+    datasets: BTreeMap<String, Dataset>,
+    crawled_at: Option<SystemTime>,
+    last_error: Option<String>,
+}
+
+impl ParticipantCatalog {
+    pub fn dataset(&self, dataset_id: &str) -> Option<&Dataset> {
+        self.datasets.get(dataset_id)
+    }
+
+    pub fn datasets(&self) -> impl Iterator<Item = &Dataset> {
+        self.datasets.values()
+    }
+
+    /// When the catalog was last crawled successfully.
+    pub fn crawled_at(&self) -> Option<SystemTime> {
+        self.crawled_at
+    }
+
+    /// The error of the last crawl, if it failed.
+    pub fn last_error(&self) -> Option<&str> {
+        self.last_error.as_deref()
+    }
+}
+
+/// A dataset of the index with the participant offering it.
+#[derive(Debug, Clone, Copy)]
+pub struct IndexedDataset<'a> {
+    participant_id: &'a str,
+    dataset: &'a Dataset,
+}
+
+impl<'a> IndexedDataset<'a> {
+    pub fn participant_id(&self) -> &'a str {
+        self.participant_id
+    }
+
+    pub fn dataset(&self) -> &'a Dataset {
+        self.dataset
+    }
+}
+
+/// Ids of the datasets changed by a crawl.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Changes {
+    added: Vec<String>,
+    updated: Vec<String>,
+    removed: Vec<String>,
+}
+
+impl Changes {
+    pub fn added(&self) -> &[String] {
+        &self.added
+    }
+
+    pub fn updated(&self) -> &[String] {
+        &self.updated
+    }
+
+    pub fn removed(&self) -> &[String] {
+        &self.removed
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.updated.is_empty() && self.removed.is_empty()
+    }
+}
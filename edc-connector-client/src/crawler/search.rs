@@ -0,0 +1,225 @@
+use serde_json::Value;
+
+use crate::types::{
+    catalog::Dataset,
+    policy::{AtomicConstraint, Constraint, Policy},
+    properties::{literal, ToValue},
+};
+
+/// Criteria of a search in the [`CatalogIndex`](super::CatalogIndex). A
+/// dataset matches when it meets all of them.
+#[derive(Debug, Clone, Default)]
+pub struct Search {
+    filters: Vec<Filter>,
+}
+
+#[derive(Debug, Clone)]
+enum Filter {
+    Participant(String),
+    Property(String, Value),
+    PropertyContains(String, String),
+    TransferType(String),
+    Constraint(String, Option<Value>),
+    Unconstrained,
+}
+
+impl Search {
+    pub fn new() -> Search {
+        Search::default()
+    }
+
+    /// Datasets offered by the participant.
+    pub fn participant(self, participant_id: &str) -> Self {
+        self.with(Filter::Participant(participant_id.to_string()))
+    }
+
+    /// Datasets with the property equal to `value`, or containing it when
+    /// the property is a list.
+    pub fn property<T: ToValue>(self, property: &str, value: T) -> Self {
+        self.with(Filter::Property(property.to_string(), value.into_value()))
+    }
+
+    /// Datasets with a text property containing `text`, ignoring the case.
+    pub fn property_contains(self, property: &str, text: &str) -> Self {
+        self.with(Filter::PropertyContains(
+            property.to_string(),
+            text.to_lowercase(),
+        ))
+    }
+
+    /// Datasets with a distribution for the transfer type, e.g.
+    /// `HttpData-PULL`.
+    pub fn transfer_type(self, transfer_type: &str) -> Self {
+        self.with(Filter::TransferType(transfer_type.to_string()))
+    }
+
+    /// Datasets with an offer constrained on `left_operand`, e.g.
+    /// `odrl:purpose`.
+    pub fn constraint(self, left_operand: &str) -> Self {
+        self.with(Filter::Constraint(left_operand.to_string(), None))
+    }
+
+    /// Datasets with an offer constraining `left_operand` to `right_operand`.
+    pub fn constraint_value<T: ToValue>(self, left_operand: &str, right_operand: T) -> Self {
+        self.with(Filter::Constraint(
+            left_operand.to_string(),
+            Some(right_operand.into_value()),
+        ))
+    }
+
+    /// Datasets with an offer without any constraint.
+    pub fn unconstrained(self) -> Self {
+        self.with(Filter::Unconstrained)
+    }
+
+    fn with(mut self, filter: Filter) -> Self {
+        self.filters.push(filter);
+        self
+    }
+
+    pub(crate) fn matches(&self, participant_id: &str, dataset: &Dataset) -> bool {
+        self.filters
+            .iter()
+            .all(|filter| filter.matches(participant_id, dataset))
+    }
+}
+
+impl Filter {
+    fn matches(&self, participant_id: &str, dataset: &Dataset) -> bool {
+        match self {
+            Filter::Participant(id) => participant_id == id,
+            Filter::Property(property, expected) => values(dataset, property)
+                .into_iter()
+                .any(|value| literal(value) == literal(expected)),
+            Filter::PropertyContains(property, text) => values(dataset, property)
+                .into_iter()
+                .filter_map(|value| literal(value).as_str())
+                .any(|value| value.to_lowercase().contains(text)),
+            Filter::TransferType(transfer_type) => dataset
+                .transfer_types()
+                .any(|candidate| candidate == transfer_type),
+            Filter::Constraint(left_operand, right_operand) => {
+                dataset.offers().iter().any(|offer| {
+                    constraints(offer).any(|constraint| {
+                        constraint.left_operand().as_str() == left_operand
+                            && right_operand
+                                .as_ref()
+                                .map(|expected| {
+                                    literal(&constraint.right_operand().0) == literal(expected)
+                                })
+                                .unwrap_or(true)
+                    })
+                })
+            }
+            Filter::Unconstrained => dataset
+                .offers()
+                .iter()
+                .any(|offer| constraints(offer).next().is_none()),
+        }
+    }
+}
+
+/// The values of a property, flattening lists.
+fn values<'a>(dataset: &'a Dataset, property: &str) -> Vec<&'a Value> {
+    match dataset.properties().get_raw(property).map(|value| &value.0) {
+        Some(Value::Array(values)) => values.iter().collect(),
+        Some(value) => vec![value],
+        None => vec![],
+    }
+}
+
+/// The atomic constraints of all the rules of an offer.
+fn constraints(offer: &Policy) -> impl Iterator<Item = &AtomicConstraint> {
+    offer
+        .permissions()
+        .iter()
+        .flat_map(|permission| permission.constraints())
+        .chain(
+            offer
+                .obligations()
+                .iter()
+                .flat_map(|obligation| obligation.constraints()),
+        )
+        .chain(
+            offer
+                .prohibitions()
+                .iter()
+                .flat_map(|prohibition| prohibition.constraints()),
+        )
+        .flat_map(Constraint::atomic_constraints)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::types::catalog::Dataset;
+
+    use super::Search;
+
+    fn dataset() -> Dataset {
+        serde_json::from_value(json!({
+            "@id": "users",
+            "odrl:hasPolicy": [
+                {
+                    "@id": "offer-1",
+                    "@type": "odrl:Offer",
+                    "odrl:permission": {
+                        "odrl:action": { "@id": "use" },
+                        "odrl:constraint": {
+                            "and": [
+                                {
+                                    "odrl:leftOperand": { "@id": "odrl:purpose" },
+                                    "odrl:operator": { "@id": "odrl:eq" },
+                                    "odrl:rightOperand": "research"
+                                }
+                            ]
+                        }
+                    }
+                }
+            ],
+            "dcat:distribution": { "dct:format": { "@id": "HttpData-PULL" } },
+            "name": "Users",
+            "keywords": ["people", "accounts"]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn should_match_properties() {
+        let dataset = dataset();
+
+        assert!(Search::new()
+            .property("name", "Users")
+            .matches("provider", &dataset));
+        assert!(Search::new()
+            .property("keywords", "accounts")
+            .matches("provider", &dataset));
+        assert!(Search::new()
+            .property_contains("name", "USER")
+            .transfer_type("HttpData-PULL")
+            .matches("provider", &dataset));
+        assert!(!Search::new()
+            .property("name", "Orders")
+            .matches("provider", &dataset));
+        assert!(!Search::new()
+            .participant("other")
+            .matches("provider", &dataset));
+    }
+
+    #[test]
+    fn should_match_offer_constraints() {
+        let dataset = dataset();
+
+        assert!(Search::new()
+            .constraint("odrl:purpose")
+            .matches("provider", &dataset));
+        assert!(Search::new()
+            .constraint_value("odrl:purpose", "research")
+            .matches("provider", &dataset));
+        assert!(!Search::new()
+            .constraint_value("odrl:purpose", "marketing")
+            .matches("provider", &dataset));
+        assert!(!Search::new().unconstrained().matches("provider", &dataset));
+    }
+}
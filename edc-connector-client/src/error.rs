@@ -8,6 +8,70 @@ pub enum Error {
 
     #[error(transparent)]
     ManagementApi(ManagementApiError),
+
+    #[error(transparent)]
+    TokenEndpoint(TokenEndpointError),
+
+    #[error(transparent)]
+    Interceptor(Box<dyn std::error::Error + Send + Sync>),
+
+    #[error(transparent)]
+    DataPlane(DataPlaneError),
+
+    #[error(transparent)]
+    Builder(#[from] BuilderError),
+
+    #[error(transparent)]
+    Workflow(WorkflowError),
+
+    #[error(transparent)]
+    Decode(DecodeError),
+
+    #[error(transparent)]
+    Conversion(#[from] ConversionError),
+}
+
+impl Error {
+    /// Whether the connector answered `404`, e.g. fetching a missing entity.
+    pub fn is_not_found(&self) -> bool {
+        self.management_api()
+            .is_some_and(ManagementApiError::is_not_found)
+    }
+
+    /// Whether the connector answered `409`, e.g. creating an entity with an
+    /// `@id` already in use.
+    pub fn is_conflict(&self) -> bool {
+        self.management_api()
+            .is_some_and(ManagementApiError::is_conflict)
+    }
+
+    /// Whether the credentials were rejected by the management api or by the
+    /// OAuth2 token endpoint.
+    pub fn is_unauthorized(&self) -> bool {
+        match self {
+            Error::ManagementApi(err) => err.is_unauthorized(),
+            Error::TokenEndpoint(err) => matches!(
+                err.status_code,
+                StatusCode::BAD_REQUEST | StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN
+            ),
+            _ => false,
+        }
+    }
+
+    /// Details of a request rejected by the connector validation, empty for
+    /// any other error.
+    pub fn validation_errors(&self) -> &[ManagementApiErrorDetail] {
+        self.management_api()
+            .map(ManagementApiError::validation_errors)
+            .unwrap_or_default()
+    }
+
+    fn management_api(&self) -> Option<&ManagementApiError> {
+        match self {
+            Error::ManagementApi(err) => Some(err),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -17,11 +81,100 @@ pub struct ManagementApiError {
     pub error_detail: ManagementApiErrorDetailKind,
 }
 
+impl ManagementApiError {
+    pub fn is_not_found(&self) -> bool {
+        self.status_code == StatusCode::NOT_FOUND
+    }
+
+    pub fn is_conflict(&self) -> bool {
+        self.status_code == StatusCode::CONFLICT
+    }
+
+    pub fn is_unauthorized(&self) -> bool {
+        matches!(
+            self.status_code,
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN
+        )
+    }
+
+    pub fn validation_errors(&self) -> &[ManagementApiErrorDetail] {
+        match &self.error_detail {
+            ManagementApiErrorDetailKind::Parsed(details)
+                if self.status_code == StatusCode::BAD_REQUEST =>
+            {
+                details
+            }
+            _ => &[],
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("OAuth2 token endpoint returned {status_code}")]
+pub struct TokenEndpointError {
+    pub status_code: StatusCode,
+    pub body: String,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Data plane public api returned {status_code}")]
+pub struct DataPlaneError {
+    pub status_code: StatusCode,
+    pub body: String,
+}
+
+/// Response of the connector that does not match the expected json.
+#[derive(Debug, thiserror::Error)]
+#[error("Failed to decode the response: {source}")]
+pub struct DecodeError {
+    pub body: String,
+    #[source]
+    pub source: serde_json::Error,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum WorkflowError {
+    #[error("Contract negotiation {id} terminated")]
+    NegotiationTerminated {
+        id: String,
+        error_detail: Option<String>,
+    },
+
+    #[error("Transfer process {id} terminated")]
+    TransferTerminated {
+        id: String,
+        error_detail: Option<String>,
+    },
+
+    #[error("Contract negotiation {0} finalized without an agreement")]
+    MissingAgreement(String),
+
+    #[error("{subject} reached the final state {state}")]
+    UnexpectedState { subject: String, state: String },
+
+    #[error("Timed out waiting for {0}")]
+    Timeout(String),
+
+    #[error("Stopped watching {0} before it reached a final state")]
+    WatchEnded(String),
+
+    #[error("The {0} is planned but not declared in the manifest")]
+    MissingFromManifest(String),
+
+    #[error("Workflow cancelled")]
+    Cancelled,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ManagementApiErrorDetail {
     pub message: String,
     #[serde(rename = "type")]
     pub kind: String,
+    /// Path of the invalid field, for validation failures.
+    #[serde(default)]
+    pub path: Option<String>,
+    #[serde(default, rename = "invalidValue")]
+    pub invalid_value: Option<serde_json::Value>,
 }
 
 #[derive(Debug)]
@@ -34,6 +187,9 @@ pub enum ManagementApiErrorDetailKind {
 pub enum BuilderError {
     #[error("Missing mandatory property {0}")]
     MissingProperty(String),
+
+    #[error("Failed to build the http client")]
+    HttpClient(#[from] reqwest::Error),
 }
 
 impl BuilderError {
@@ -42,6 +198,153 @@ impl BuilderError {
     }
 }
 
-#[derive(Debug, thiserror::Error, PartialEq)]
-#[error("Failed to convert")]
-pub struct ConversionError {}
+/// Failure converting a property value, with the property path, the expected
+/// type and the kind of JSON value found.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+#[error("{}: expected {expected}, found {actual}", self.subject())]
+pub struct ConversionError {
+    path: Vec<PathSegment>,
+    field: Option<String>,
+    expected: &'static str,
+    actual: ValueKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum PathSegment {
+    Key(String),
+    Index(usize),
+}
+
+/// Kind of the JSON value that failed to convert.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueKind {
+    Null,
+    Bool,
+    Number,
+    String,
+    Array,
+    Object,
+    Missing,
+}
+
+impl ValueKind {
+    pub(crate) fn of(value: &serde_json::Value) -> ValueKind {
+        match value {
+            serde_json::Value::Null => ValueKind::Null,
+            serde_json::Value::Bool(_) => ValueKind::Bool,
+            serde_json::Value::Number(_) => ValueKind::Number,
+            serde_json::Value::String(_) => ValueKind::String,
+            serde_json::Value::Array(_) => ValueKind::Array,
+            serde_json::Value::Object(_) => ValueKind::Object,
+        }
+    }
+}
+
+impl std::fmt::Display for ValueKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = match self {
+            ValueKind::Null => "null",
+            ValueKind::Bool => "boolean",
+            ValueKind::Number => "number",
+            ValueKind::String => "string",
+            ValueKind::Array => "array",
+            ValueKind::Object => "object",
+            ValueKind::Missing => "nothing",
+        };
+        write!(f, "{}", kind)
+    }
+}
+
+impl ConversionError {
+    pub(crate) fn new(expected: &'static str, actual: &serde_json::Value) -> ConversionError {
+        ConversionError {
+            path: vec![],
+            field: None,
+            expected,
+            actual: ValueKind::of(actual),
+        }
+    }
+
+    pub(crate) fn missing_field(
+        field: &str,
+        property: &str,
+        expected: &'static str,
+    ) -> ConversionError {
+        ConversionError {
+            path: vec![PathSegment::Key(property.to_string())],
+            field: Some(field.to_string()),
+            expected,
+            actual: ValueKind::Missing,
+        }
+    }
+
+    pub(crate) fn with_field(mut self, field: &str) -> ConversionError {
+        self.field = Some(field.to_string());
+        self
+    }
+
+    /// Prepends the property or map key holding the value.
+    pub(crate) fn at_key(mut self, key: &str) -> ConversionError {
+        self.path.insert(0, PathSegment::Key(key.to_string()));
+        self
+    }
+
+    /// Prepends the position of the value in a list.
+    pub(crate) fn at_index(mut self, index: usize) -> ConversionError {
+        self.path.insert(0, PathSegment::Index(index));
+        self
+    }
+
+    /// Path of the value from the property, e.g. `dimensions.width` or `tags[2]`.
+    pub fn path(&self) -> String {
+        let mut path = String::new();
+        for segment in &self.path {
+            match segment {
+                PathSegment::Key(key) if path.is_empty() => path.push_str(key),
+                PathSegment::Key(key) => {
+                    path.push('.');
+                    path.push_str(key);
+                }
+                PathSegment::Index(index) => path.push_str(&format!("[{}]", index)),
+            }
+        }
+        path
+    }
+
+    /// Field of the `EdcProperties` struct that failed to convert.
+    pub fn field(&self) -> Option<&str> {
+        self.field.as_deref()
+    }
+
+    /// Type the value was converted to.
+    pub fn expected(&self) -> &str {
+        self.expected
+    }
+
+    /// Kind of the value found.
+    pub fn actual(&self) -> ValueKind {
+        self.actual
+    }
+
+    /// Whether the property of a required field was not found.
+    pub fn is_missing(&self) -> bool {
+        self.actual == ValueKind::Missing
+    }
+
+    /// The failure and the value it is about, e.g.
+    /// ``Failed to convert property `tags[2]` of field `tags` ``.
+    fn subject(&self) -> String {
+        let mut subject = if self.is_missing() {
+            "Missing property".to_string()
+        } else {
+            "Failed to convert property".to_string()
+        };
+        if !self.path.is_empty() {
+            subject.push_str(&format!(" `{}`", self.path()));
+        }
+        if let Some(field) = &self.field {
+            subject.push_str(&format!(" of field `{}`", field));
+        }
+        subject
+    }
+}
@@ -31,15 +31,32 @@
 //!
 
 mod api;
+#[cfg(feature = "callbacks")]
+pub mod callbacks;
 mod client;
+#[cfg(feature = "crawler")]
+pub mod crawler;
 mod error;
+#[cfg(feature = "reconcile")]
+pub mod reconcile;
+#[cfg(feature = "testing")]
+pub mod testing;
 
 pub mod types;
 
-pub use client::{Auth, EdcConnectorClient};
+pub use api::{
+    AgreementStore, AssetStore, CatalogService, ContractDefinitionStore, DataPlaneRegistry,
+    EdrStore, NegotiationService, PolicyStore, SecretStore, TransferService, UpsertOutcome,
+    WaitOptions, WaitOptionsBuilder,
+};
+pub use client::{
+    Auth, EdcConnectorClient, Interceptor, OAuth2ClientCredentials, OAuth2ClientCredentialsBuilder,
+    RetryPolicy, RetryPolicyBuilder,
+};
 pub use error::{
-    BuilderError, ConversionError, Error, ManagementApiError, ManagementApiErrorDetail,
-    ManagementApiErrorDetailKind,
+    BuilderError, ConversionError, DataPlaneError, DecodeError, Error, ManagementApiError,
+    ManagementApiErrorDetail, ManagementApiErrorDetailKind, TokenEndpointError, ValueKind,
+    WorkflowError,
 };
 
 pub const EDC_NAMESPACE: &str = "https://w3id.org/edc/v0.0.1/ns/";
@@ -0,0 +1,542 @@
+//! Declarative reconciliation of the offerings of a provider connector.
+//!
+//! A [`Manifest`] lists the desired assets, policy definitions and contract
+//! definitions. [`Reconcile`] diffs it against the connector and creates,
+//! updates and, when pruning, deletes resources so that the connector matches
+//! the manifest.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api::{
+        assets::AssetApi,
+        contract_definitions::ContractDefinitionApi,
+        policies::PolicyApi,
+        upsert::{is_unchanged, ASSET_MANAGED_VALUES, POLICY_DEFINITION_MANAGED_VALUES},
+    },
+    client::EdcConnectorClientInternal,
+    types::{
+        asset::NewAsset, contract_definition::NewContractDefinition, policy::NewPolicyDefinition,
+        query::Query,
+    },
+    EdcResult, Error, WorkflowError,
+};
+
+/// Desired state of the offerings of a connector.
+///
+/// Every resource must have an `@id`, which is used to match it with the
+/// resources stored in the connector.
+///
+/// ```yaml
+/// policyDefinitions:
+///   - "@id": open-policy
+///     policy:
+///       "@type": Set
+/// assets:
+///   - "@id": users
+///     properties:
+///       name: Users
+///     dataAddress:
+///       type: HttpData
+///       baseUrl: https://jsonplaceholder.typicode.com/users
+/// contractDefinitions:
+///   - "@id": users-offer
+///     accessPolicyId: open-policy
+///     contractPolicyId: open-policy
+///     assetsSelector:
+///       - operandLeft: https://w3id.org/edc/v0.0.1/ns/id
+///         operator: "="
+///         operandRight: users
+/// ```
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", try_from = "UncheckedManifest")]
+pub struct Manifest {
+    assets: Vec<NewAsset>,
+    policy_definitions: Vec<NewPolicyDefinition>,
+    contract_definitions: Vec<NewContractDefinition>,
+}
+
+/// A manifest as written, before its ids are validated.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UncheckedManifest {
+    #[serde(default)]
+    assets: Vec<NewAsset>,
+    #[serde(default)]
+    policy_definitions: Vec<NewPolicyDefinition>,
+    #[serde(default)]
+    contract_definitions: Vec<NewContractDefinition>,
+}
+
+impl TryFrom<UncheckedManifest> for Manifest {
+    type Error = ManifestError;
+
+    fn try_from(manifest: UncheckedManifest) -> Result<Self, Self::Error> {
+        Manifest {
+            assets: manifest.assets,
+            policy_definitions: manifest.policy_definitions,
+            contract_definitions: manifest.contract_definitions,
+        }
+        .validated()
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ManifestError {
+    #[error("Failed to parse the json manifest")]
+    Json(#[from] serde_json::Error),
+
+    #[error("Failed to parse the yaml manifest")]
+    Yaml(#[from] serde_yaml::Error),
+
+    #[error("The {kind} at position {position} has no @id")]
+    MissingId { kind: ResourceKind, position: usize },
+
+    #[error("The {kind} {id} is declared more than once")]
+    DuplicateId { kind: ResourceKind, id: String },
+}
+
+impl Manifest {
+    pub fn builder() -> ManifestBuilder {
+        ManifestBuilder::default()
+    }
+
+    pub fn from_json(json: &str) -> Result<Manifest, ManifestError> {
+        serde_json::from_str::<UncheckedManifest>(json)?.try_into()
+    }
+
+    pub fn from_yaml(yaml: &str) -> Result<Manifest, ManifestError> {
+        serde_yaml::from_str::<UncheckedManifest>(yaml)?.try_into()
+    }
+
+    pub fn assets(&self) -> &[NewAsset] {
+        &self.assets
+    }
+
+    pub fn policy_definitions(&self) -> &[NewPolicyDefinition] {
+        &self.policy_definitions
+    }
+
+    pub fn contract_definitions(&self) -> &[NewContractDefinition] {
+        &self.contract_definitions
+    }
+
+    fn validated(self) -> Result<Manifest, ManifestError> {
+        validate_ids(ResourceKind::Asset, self.assets.iter().map(NewAsset::id))?;
+        validate_ids(
+            ResourceKind::PolicyDefinition,
+            self.policy_definitions.iter().map(NewPolicyDefinition::id),
+        )?;
+        validate_ids(
+            ResourceKind::ContractDefinition,
+            self.contract_definitions
+                .iter()
+                .map(NewContractDefinition::id),
+        )?;
+        Ok(self)
+    }
+}
+
+fn validate_ids<'a>(
+    kind: ResourceKind,
+    ids: impl Iterator<Item = Option<&'a str>>,
+) -> Result<(), ManifestError> {
+    let mut seen = HashSet::new();
+    for (position, id) in ids.enumerate() {
+        let id = id.ok_or(ManifestError::MissingId { kind, position })?;
+        if !seen.insert(id) {
+            return Err(ManifestError::DuplicateId {
+                kind,
+                id: id.to_string(),
+            });
+        }
+    }
+    Ok(())
+}
+
+#[derive(Default)]
+pub struct ManifestBuilder(Manifest);
+
+impl ManifestBuilder {
+    pub fn asset(mut self, asset: NewAsset) -> Self {
+        self.0.assets.push(asset);
+        self
+    }
+
+    pub fn policy_definition(mut self, policy_definition: NewPolicyDefinition) -> Self {
+        self.0.policy_definitions.push(policy_definition);
+        self
+    }
+
+    pub fn contract_definition(mut self, contract_definition: NewContractDefinition) -> Self {
+        self.0.contract_definitions.push(contract_definition);
+        self
+    }
+
+    pub fn build(self) -> Result<Manifest, ManifestError> {
+        self.0.validated()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ResourceKind {
+    Asset,
+    PolicyDefinition,
+    ContractDefinition,
+}
+
+impl Display for ResourceKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResourceKind::Asset => write!(f, "asset"),
+            ResourceKind::PolicyDefinition => write!(f, "policy definition"),
+            ResourceKind::ContractDefinition => write!(f, "contract definition"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Change {
+    Create,
+    Update,
+    Delete,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Action {
+    kind: ResourceKind,
+    id: String,
+    change: Change,
+}
+
+impl Action {
+    fn new(kind: ResourceKind, id: &str, change: Change) -> Action {
+        Action {
+            kind,
+            id: id.to_string(),
+            change,
+        }
+    }
+
+    pub fn kind(&self) -> ResourceKind {
+        self.kind
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn change(&self) -> Change {
+        self.change
+    }
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = match self.change {
+            Change::Create => '+',
+            Change::Update => '~',
+            Change::Delete => '-',
+        };
+        write!(f, "{} {} {}", sign, self.kind, self.id)
+    }
+}
+
+/// Ordered actions bringing the connector to the desired state.
+///
+/// Policy definitions and assets are written before the contract definitions
+/// referencing them, and contract definitions are deleted before them.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct Plan {
+    actions: Vec<Action>,
+}
+
+impl Plan {
+    pub fn actions(&self) -> &[Action] {
+        &self.actions
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+}
+
+impl Display for Plan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.actions.is_empty() {
+            return write!(f, "No changes");
+        }
+        for action in &self.actions {
+            writeln!(f, "{}", action)?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Reconcile<'a> {
+    client: &'a EdcConnectorClientInternal,
+    manifest: &'a Manifest,
+    prune: bool,
+}
+
+impl<'a> Reconcile<'a> {
+    pub(crate) fn new(
+        client: &'a EdcConnectorClientInternal,
+        manifest: &'a Manifest,
+    ) -> Reconcile<'a> {
+        Reconcile {
+            client,
+            manifest,
+            prune: false,
+        }
+    }
+
+    /// Deletes the resources of the connector missing from the manifest.
+    ///
+    /// Disabled by default, since it removes anything created outside of the
+    /// manifest.
+    pub fn prune(mut self, prune: bool) -> Self {
+        self.prune = prune;
+        self
+    }
+
+    /// Computes the actions without applying them.
+    pub async fn plan(&self) -> EdcResult<Plan> {
+        let policies = PolicyApi::new(self.client)
+            .query_all(Query::default())
+            .await?
+            .into_iter()
+            .map(|policy| (policy.id().to_string(), policy))
+            .collect::<HashMap<_, _>>();
+        let assets = AssetApi::new(self.client)
+            .query_all(Query::default())
+            .await?
+            .into_iter()
+            .map(|asset| (asset.id().to_string(), asset))
+            .collect::<HashMap<_, _>>();
+        let contract_definitions = ContractDefinitionApi::new(self.client)
+            .query_all(Query::default())
+            .await?
+            .into_iter()
+            .map(|definition| (definition.id().to_string(), definition))
+            .collect::<HashMap<_, _>>();
+
+        let mut plan = Plan::default();
+
+        diff(
+            &mut plan,
+            ResourceKind::PolicyDefinition,
+            &self.manifest.policy_definitions,
+            NewPolicyDefinition::id,
+            &policies,
+            POLICY_DEFINITION_MANAGED_VALUES,
+        );
+        diff(
+            &mut plan,
+            ResourceKind::Asset,
+            &self.manifest.assets,
+            NewAsset::id,
+            &assets,
+            ASSET_MANAGED_VALUES,
+        );
+        diff(
+            &mut plan,
+            ResourceKind::ContractDefinition,
+            &self.manifest.contract_definitions,
+            NewContractDefinition::id,
+            &contract_definitions,
+            &[],
+        );
+
+        if self.prune {
+            prune(
+                &mut plan,
+                ResourceKind::ContractDefinition,
+                &self.manifest.contract_definitions,
+                NewContractDefinition::id,
+                &contract_definitions,
+            );
+            prune(
+                &mut plan,
+                ResourceKind::Asset,
+                &self.manifest.assets,
+                NewAsset::id,
+                &assets,
+            );
+            prune(
+                &mut plan,
+                ResourceKind::PolicyDefinition,
+                &self.manifest.policy_definitions,
+                NewPolicyDefinition::id,
+                &policies,
+            );
+        }
+
+        Ok(plan)
+    }
+
+    /// Computes and applies the actions, returning the applied plan.
+    ///
+    /// Actions are applied in order and the first failure stops the
+    /// reconciliation.
+    pub async fn apply(&self) -> EdcResult<Plan> {
+        let plan = self.plan().await?;
+
+        for action in plan.actions() {
+            self.apply_action(action).await?;
+        }
+
+        Ok(plan)
+    }
+
+    async fn apply_action(&self, action: &Action) -> EdcResult<()> {
+        let id = action.id();
+        match (action.kind(), action.change()) {
+            (ResourceKind::Asset, Change::Delete) => AssetApi::new(self.client).delete(id).await,
+            (ResourceKind::Asset, change) => {
+                let asset = find(ResourceKind::Asset, &self.manifest.assets, NewAsset::id, id)?;
+                let api = AssetApi::new(self.client);
+                match change {
+                    Change::Create => api.create(asset).await.map(|_| ()),
+                    _ => api.update(&asset.to_asset(id)).await,
+                }
+            }
+            (ResourceKind::PolicyDefinition, Change::Delete) => {
+                PolicyApi::new(self.client).delete(id).await
+            }
+            (ResourceKind::PolicyDefinition, change) => {
+                let policy_definition = find(
+                    ResourceKind::PolicyDefinition,
+                    &self.manifest.policy_definitions,
+                    NewPolicyDefinition::id,
+                    id,
+                )?;
+                let api = PolicyApi::new(self.client);
+                match change {
+                    Change::Create => api.create(policy_definition).await.map(|_| ()),
+                    _ => {
+                        api.update(&policy_definition.to_policy_definition(id))
+                            .await
+                    }
+                }
+            }
+            (ResourceKind::ContractDefinition, Change::Delete) => {
+                ContractDefinitionApi::new(self.client).delete(id).await
+            }
+            (ResourceKind::ContractDefinition, change) => {
+                let contract_definition = find(
+                    ResourceKind::ContractDefinition,
+                    &self.manifest.contract_definitions,
+                    NewContractDefinition::id,
+                    id,
+                )?;
+                let api = ContractDefinitionApi::new(self.client);
+                match change {
+                    Change::Create => api.create(contract_definition).await.map(|_| ()),
+                    _ => {
+                        api.update(&contract_definition.to_contract_definition(id))
+                            .await
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn diff<D, C>(
+    plan: &mut Plan,
+    kind: ResourceKind,
+    desired: &[D],
+    id: impl Fn(&D) -> Option<&str>,
+    current: &HashMap<String, C>,
+    managed: &[&str],
+) where
+    D: Serialize,
+    C: Serialize,
+{
+    for resource in desired {
+        let Some(resource_id) = id(resource) else {
+            continue;
+        };
+        match current.get(resource_id) {
+            None => plan
+                .actions
+                .push(Action::new(kind, resource_id, Change::Create)),
+            Some(stored) if !is_unchanged(resource, stored, managed) => plan
+                .actions
+                .push(Action::new(kind, resource_id, Change::Update)),
+            Some(_) => {}
+        }
+    }
+}
+
+fn prune<D, C>(
+    plan: &mut Plan,
+    kind: ResourceKind,
+    desired: &[D],
+    id: impl Fn(&D) -> Option<&str>,
+    current: &HashMap<String, C>,
+) {
+    let desired = desired.iter().filter_map(id).collect::<HashSet<_>>();
+    let mut stale = current
+        .keys()
+        .filter(|stored| !desired.contains(stored.as_str()))
+        .collect::<Vec<_>>();
+    stale.sort();
+
+    plan.actions.extend(
+        stale
+            .into_iter()
+            .map(|stored| Action::new(kind, stored, Change::Delete)),
+    );
+}
+
+/// The declared resource a created or updated resource of the plan comes from.
+fn find<'m, D>(
+    kind: ResourceKind,
+    desired: &'m [D],
+    id: impl Fn(&D) -> Option<&str>,
+    target: &str,
+) -> EdcResult<&'m D> {
+    desired
+        .iter()
+        .find(|resource| id(resource) == Some(target))
+        .ok_or_else(|| {
+            Error::Workflow(WorkflowError::MissingFromManifest(format!(
+                "{} {}",
+                kind, target
+            )))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        types::{asset::NewAsset, data_address::DataAddress},
+        Error, WorkflowError,
+    };
+
+    use super::{find, ResourceKind};
+
+    #[test]
+    fn should_fail_on_a_planned_resource_missing_from_the_manifest() {
+        let assets = vec![NewAsset::builder()
+            .id("users")
+            .data_address(DataAddress::builder().kind("HttpData").build().unwrap())
+            .build()
+            .unwrap()];
+
+        assert!(find(ResourceKind::Asset, &assets, NewAsset::id, "users").is_ok());
+        assert!(matches!(
+            find(ResourceKind::Asset, &assets, NewAsset::id, "orders"),
+            Err(Error::Workflow(WorkflowError::MissingFromManifest(subject))) if subject == "asset orders"
+        ));
+    }
+}
@@ -0,0 +1,180 @@
+//! In-memory fake of the management API, for testing code built on
+//! [`EdcConnectorClient`] without a running connector.
+//!
+//! Enabled with the `testing` feature.
+//!
+//! The fake stores assets, policy definitions, contract definitions,
+//! contract negotiations, contract agreements, transfer processes and EDRs,
+//! evaluates the filters, sorting and paging of queries and moves
+//! negotiations and transfers through their states in the background:
+//!
+//! - negotiations: `REQUESTING`, `REQUESTED`, `AGREED`, `VERIFIED`, `FINALIZED`.
+//!   The agreement is created once finalized.
+//! - transfers: `REQUESTING`, `REQUESTED`, `STARTED`. Pull transfers get an
+//!   EDR once started. Transfers of unknown agreements end up `TERMINATED`.
+//!
+//! ```rust,no_run
+//! use edc_connector_client::{
+//!     testing::FakeConnector,
+//!     types::{asset::NewAsset, data_address::DataAddress},
+//! };
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let connector = FakeConnector::start().await?;
+//!     let client = connector.client()?;
+//!
+//!     let asset = NewAsset::builder()
+//!         .id("users")
+//!         .data_address(DataAddress::builder().kind("HttpData").build()?)
+//!         .build()?;
+//!
+//!     client.assets().create(&asset).await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+mod query;
+mod routes;
+mod store;
+mod workflow;
+
+use std::{
+    net::SocketAddr,
+    sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
+};
+
+use tokio::{net::TcpListener, task::JoinHandle};
+
+use crate::{Auth, BuilderError, EdcConnectorClient};
+
+use self::store::Store;
+
+/// Fake connector serving the management API on a local port.
+///
+/// The server stops when the fake is dropped.
+pub struct FakeConnector {
+    address: SocketAddr,
+    api_key: Option<String>,
+    server: JoinHandle<()>,
+}
+
+impl FakeConnector {
+    pub fn builder() -> FakeConnectorBuilder {
+        FakeConnectorBuilder::default()
+    }
+
+    /// Starts a fake connector with the default settings.
+    pub async fn start() -> std::io::Result<FakeConnector> {
+        FakeConnector::builder().start().await
+    }
+
+    pub fn management_url(&self) -> String {
+        format!("http://{}/management", self.address)
+    }
+
+    /// A client for the management API of the fake, authenticated with the
+    /// API key if one is required.
+    pub fn client(&self) -> Result<EdcConnectorClient, BuilderError> {
+        let builder = EdcConnectorClient::builder().management_url(self.management_url());
+
+        match &self.api_key {
+            Some(api_key) => builder.with_auth(Auth::api_token(api_key)),
+            None => builder,
+        }
+        .build()
+    }
+}
+
+impl Drop for FakeConnector {
+    fn drop(&mut self) {
+        self.server.abort();
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Config {
+    participant_id: String,
+    api_key: Option<String>,
+    state_delay: Duration,
+    public_endpoint: String,
+}
+
+/// State shared by the routes and the background workflows.
+#[derive(Clone)]
+pub(crate) struct Fake {
+    config: Arc<Config>,
+    store: Arc<Mutex<Store>>,
+}
+
+impl Fake {
+    pub(crate) fn store(&self) -> MutexGuard<'_, Store> {
+        self.store.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+pub struct FakeConnectorBuilder(Config);
+
+impl Default for FakeConnectorBuilder {
+    fn default() -> Self {
+        FakeConnectorBuilder(Config {
+            participant_id: "consumer".to_string(),
+            api_key: None,
+            state_delay: Duration::from_millis(10),
+            public_endpoint: "http://localhost:8185/api/public".to_string(),
+        })
+    }
+}
+
+impl FakeConnectorBuilder {
+    /// Id of the fake, used as consumer of the agreements. Defaults to `consumer`.
+    pub fn participant_id(mut self, participant_id: &str) -> Self {
+        self.0.participant_id = participant_id.to_string();
+        self
+    }
+
+    /// Requires requests to carry the API key in the `X-Api-Key` header.
+    pub fn api_key(mut self, api_key: &str) -> Self {
+        self.0.api_key = Some(api_key.to_string());
+        self
+    }
+
+    /// Time between two state transitions of negotiations and transfers.
+    /// Defaults to 10 milliseconds.
+    pub fn state_delay(mut self, state_delay: Duration) -> Self {
+        self.0.state_delay = state_delay;
+        self
+    }
+
+    /// Endpoint of the data addresses of EDRs, e.g. the URL of a mock data
+    /// plane.
+    pub fn public_endpoint(mut self, public_endpoint: &str) -> Self {
+        self.0.public_endpoint = public_endpoint.to_string();
+        self
+    }
+
+    /// Binds the fake to a free local port and serves it in the background.
+    pub async fn start(self) -> std::io::Result<FakeConnector> {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await?;
+        let address = listener.local_addr()?;
+        let api_key = self.0.api_key.clone();
+
+        let fake = Fake {
+            config: Arc::new(self.0),
+            store: Arc::new(Mutex::new(Store::default())),
+        };
+
+        let router = routes::router(fake);
+        let server = tokio::spawn(async move {
+            let _ = axum::serve(listener, router).await;
+        });
+
+        Ok(FakeConnector {
+            address,
+            api_key,
+            server,
+        })
+    }
+}
@@ -0,0 +1,254 @@
+use std::cmp::Ordering;
+
+use serde_json::Value;
+
+use crate::EDC_NAMESPACE;
+
+use super::store::FakeError;
+
+const DEFAULT_LIMIT: usize = 50;
+
+/// Applies the filters, the sorting and the paging of a query.
+pub(crate) fn evaluate(entries: &[Value], query: &Value) -> Result<Vec<Value>, FakeError> {
+    let criteria = match &query["filterExpression"] {
+        Value::Array(criteria) => criteria.iter().collect(),
+        Value::Null => vec![],
+        criterion => vec![criterion],
+    };
+
+    let mut matching = vec![];
+    for entry in entries {
+        if criteria
+            .iter()
+            .try_fold(true, |all, criterion| Ok(all && matches(entry, criterion)?))?
+        {
+            matching.push(entry.clone());
+        }
+    }
+
+    if let Some(field) = query["sortField"].as_str() {
+        matching.sort_by(|left, right| {
+            match (resolve(left, field).first(), resolve(right, field).first()) {
+                (Some(left), Some(right)) => compare(left, right).unwrap_or(Ordering::Equal),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            }
+        });
+
+        if query["sortOrder"].as_str() == Some("DESC") {
+            matching.reverse();
+        }
+    }
+
+    let offset = query["offset"].as_u64().unwrap_or_default() as usize;
+    let limit = query["limit"]
+        .as_u64()
+        .map(|limit| limit as usize)
+        .unwrap_or(DEFAULT_LIMIT);
+
+    Ok(matching.into_iter().skip(offset).take(limit).collect())
+}
+
+fn matches(entry: &Value, criterion: &Value) -> Result<bool, FakeError> {
+    let left = criterion["operandLeft"].as_str().unwrap_or_default();
+    let operator = criterion["operator"].as_str().unwrap_or_default();
+    let right = &criterion["operandRight"];
+    let values = resolve(entry, left);
+
+    let any = |predicate: &dyn Fn(&Value) -> bool| values.iter().any(|value| predicate(value));
+
+    Ok(match operator {
+        "=" | "contains" => any(&|value| equals(value, right)),
+        "!=" => !any(&|value| equals(value, right)),
+        "in" => {
+            let options = match right {
+                Value::Array(options) => options.iter().collect::<Vec<_>>(),
+                option => vec![option],
+            };
+            any(&|value| options.iter().any(|option| equals(value, option)))
+        }
+        "like" | "ilike" => {
+            let pattern = literal(right).as_str().unwrap_or_default();
+            let insensitive = operator == "ilike";
+            any(&|value| {
+                literal(value)
+                    .as_str()
+                    .map(|value| like(value, pattern, insensitive))
+                    .unwrap_or(false)
+            })
+        }
+        "<" => any(&|value| compare(value, right) == Some(Ordering::Less)),
+        "<=" => any(&|value| compare(value, right).is_some_and(Ordering::is_le)),
+        ">" => any(&|value| compare(value, right) == Some(Ordering::Greater)),
+        ">=" => any(&|value| compare(value, right).is_some_and(Ordering::is_ge)),
+        other => {
+            return Err(FakeError::Validation(format!(
+                "Operator [{}] is not supported",
+                other
+            )))
+        }
+    })
+}
+
+/// Values at `path`, either a property of the entry, of its `properties` or
+/// a dotted path. Arrays are flattened.
+pub(crate) fn resolve<'a>(entry: &'a Value, path: &str) -> Vec<&'a Value> {
+    let path = path.strip_prefix(EDC_NAMESPACE).unwrap_or(path);
+
+    if path == "id" || path == "@id" {
+        return vec![&entry["@id"]];
+    }
+
+    if let Some(value) = entry
+        .get(path)
+        .or_else(|| entry.get("properties").and_then(|p| p.get(path)))
+        .or_else(|| {
+            entry
+                .get("properties")
+                .and_then(|p| p.get(EDC_NAMESPACE.to_string() + path))
+        })
+    {
+        return flatten(vec![value]);
+    }
+
+    path.split('.')
+        .fold(vec![entry], |values, segment| {
+            let segment = segment.strip_prefix(EDC_NAMESPACE).unwrap_or(segment);
+            flatten(values)
+                .into_iter()
+                .filter_map(|value| value.get(segment))
+                .collect()
+        })
+        .into_iter()
+        .flat_map(|value| flatten(vec![value]))
+        .collect()
+}
+
+fn flatten(values: Vec<&Value>) -> Vec<&Value> {
+    values
+        .into_iter()
+        .flat_map(|value| match value {
+            Value::Array(values) => values.iter().collect(),
+            value => vec![value],
+        })
+        .collect()
+}
+
+/// Unwraps JSON-LD value objects.
+fn literal(value: &Value) -> &Value {
+    value.get("@value").unwrap_or(value)
+}
+
+fn equals(left: &Value, right: &Value) -> bool {
+    let (left, right) = (literal(left), literal(right));
+    left == right || compare(left, right) == Some(Ordering::Equal)
+}
+
+/// Numbers and numeric strings compare as numbers, other scalars as text.
+fn compare(left: &Value, right: &Value) -> Option<Ordering> {
+    let (left, right) = (literal(left), literal(right));
+
+    match (number(left), number(right)) {
+        (Some(left), Some(right)) => left.partial_cmp(&right),
+        _ => Some(text(left)?.cmp(&text(right)?)),
+    }
+}
+
+fn number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(number) => number.as_f64(),
+        Value::String(string) => string.parse().ok(),
+        _ => None,
+    }
+}
+
+fn text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(string) => Some(string.clone()),
+        Value::Bool(_) | Value::Number(_) => Some(value.to_string()),
+        _ => None,
+    }
+}
+
+/// SQL `LIKE` matching, `%` matches any sequence and `_` any character.
+fn like(value: &str, pattern: &str, insensitive: bool) -> bool {
+    fn matches(value: &[char], pattern: &[char]) -> bool {
+        match pattern.split_first() {
+            None => value.is_empty(),
+            Some(('%', rest)) => (0..=value.len()).any(|skip| matches(&value[skip..], rest)),
+            Some(('_', rest)) => !value.is_empty() && matches(&value[1..], rest),
+            Some((c, rest)) => value.first() == Some(c) && matches(&value[1..], rest),
+        }
+    }
+
+    let chars = |text: &str| {
+        if insensitive {
+            text.to_lowercase().chars().collect::<Vec<_>>()
+        } else {
+            text.chars().collect()
+        }
+    };
+
+    matches(&chars(value), &chars(pattern))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::evaluate;
+
+    fn entries() -> Vec<Value> {
+        vec![
+            json!({ "@id": "a", "state": "FINALIZED", "createdAt": 3, "properties": { "name": "Users" } }),
+            json!({ "@id": "b", "state": "TERMINATED", "createdAt": 1, "properties": { "name": "Orders" } }),
+            json!({ "@id": "c", "state": "FINALIZED", "createdAt": 2, "policy": { "permission": [{ "action": "use" }] } }),
+        ]
+    }
+
+    fn ids(query: Value) -> Vec<String> {
+        evaluate(&entries(), &query)
+            .unwrap()
+            .iter()
+            .map(|entry| entry["@id"].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    fn filter(left: &str, operator: &str, right: Value) -> Value {
+        json!({ "filterExpression": [{ "operandLeft": left, "operator": operator, "operandRight": right }] })
+    }
+
+    #[test]
+    fn should_filter_entries() {
+        assert_eq!(ids(filter("state", "=", json!("FINALIZED"))), ["a", "c"]);
+        assert_eq!(
+            ids(filter(
+                "https://w3id.org/edc/v0.0.1/ns/id",
+                "in",
+                json!(["b", "c"])
+            )),
+            ["b", "c"]
+        );
+        assert_eq!(ids(filter("name", "ilike", json!("%user%"))), ["a"]);
+        assert_eq!(ids(filter("createdAt", ">=", json!("2"))), ["a", "c"]);
+        assert_eq!(
+            ids(filter("policy.permission.action", "=", json!("use"))),
+            ["c"]
+        );
+        assert_eq!(ids(filter("state", "!=", json!("FINALIZED"))), ["b"]);
+    }
+
+    #[test]
+    fn should_sort_and_page_entries() {
+        assert_eq!(
+            ids(json!({ "sortField": "createdAt", "sortOrder": "DESC", "offset": 1, "limit": 1 })),
+            ["c"]
+        );
+    }
+
+    #[test]
+    fn should_reject_unknown_operators() {
+        assert!(evaluate(&entries(), &filter("state", "between", json!("A"))).is_err());
+    }
+}
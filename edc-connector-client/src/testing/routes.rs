@@ -0,0 +1,434 @@
+use axum::{
+    body::Bytes,
+    extract::{Path, Request, State},
+    http::StatusCode,
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::{get, post, MethodRouter},
+    Json, Router,
+};
+use serde_json::{json, Value};
+
+use crate::EDC_NAMESPACE;
+
+use super::{
+    store::{now, FakeError, Kind},
+    workflow, Fake,
+};
+
+type Reply = Result<Response, FakeError>;
+
+pub(crate) fn router(fake: Fake) -> Router {
+    let api = Router::new()
+        .merge(definitions("/v3/assets", Kind::Asset))
+        .merge(definitions("/v3/policydefinitions", Kind::PolicyDefinition))
+        .merge(definitions(
+            "/v3/contractdefinitions",
+            Kind::ContractDefinition,
+        ))
+        .route(
+            "/v2/policydefinitions/:id",
+            update_by_id(Kind::PolicyDefinition),
+        )
+        .route(
+            "/v3/policydefinitions/:id",
+            update_by_id(Kind::PolicyDefinition),
+        )
+        .route("/v3/contractnegotiations", post(initiate_negotiation))
+        .route(
+            "/v3/contractnegotiations/request",
+            query(Kind::ContractNegotiation),
+        )
+        .route(
+            "/v3/contractnegotiations/:id",
+            get_by_id(Kind::ContractNegotiation),
+        )
+        .route(
+            "/v3/contractnegotiations/:id/state",
+            state(Kind::ContractNegotiation, "NegotiationState"),
+        )
+        .route(
+            "/v3/contractnegotiations/:id/agreement",
+            get(negotiation_agreement),
+        )
+        .route(
+            "/v3/contractnegotiations/:id/terminate",
+            terminate(Kind::ContractNegotiation),
+        )
+        .route(
+            "/v3/contractagreements/request",
+            query(Kind::ContractAgreement),
+        )
+        .route(
+            "/v3/contractagreements/:id",
+            get_by_id(Kind::ContractAgreement),
+        )
+        .route(
+            "/v3/contractagreements/:id/negotiation",
+            get(agreement_negotiation),
+        )
+        .route("/v3/transferprocesses", post(initiate_transfer))
+        .route(
+            "/v3/transferprocesses/request",
+            query(Kind::TransferProcess),
+        )
+        .route(
+            "/v3/transferprocesses/:id",
+            get_by_id(Kind::TransferProcess),
+        )
+        .route(
+            "/v3/transferprocesses/:id/state",
+            state(Kind::TransferProcess, "TransferState"),
+        )
+        .route(
+            "/v3/transferprocesses/:id/terminate",
+            terminate(Kind::TransferProcess),
+        )
+        .route(
+            "/v3/transferprocesses/:id/suspend",
+            set_transfer_state("SUSPENDED"),
+        )
+        .route(
+            "/v3/transferprocesses/:id/resume",
+            set_transfer_state("STARTED"),
+        )
+        .route("/v3/edrs/request", query(Kind::Edr))
+        .route("/v3/edrs/:id", delete_edr())
+        .route("/v3/edrs/:id/dataaddress", get(edr_data_address))
+        .route("/v3/edrs/:id/refresh", post(refresh_edr));
+
+    Router::new()
+        .nest("/management", api)
+        .layer(middleware::from_fn_with_state(fake.clone(), authenticate))
+        .with_state(fake)
+}
+
+async fn authenticate(State(fake): State<Fake>, request: Request, next: Next) -> Response {
+    match &fake.config.api_key {
+        Some(api_key)
+            if request
+                .headers()
+                .get("X-Api-Key")
+                .map(|value| value.as_bytes())
+                != Some(api_key.as_bytes()) =>
+        {
+            FakeError::Unauthorized.into_response()
+        }
+        _ => next.run(request).await,
+    }
+}
+
+/// Create, update, get, delete and query of assets, policy definitions and
+/// contract definitions.
+fn definitions(path: &str, kind: Kind) -> Router<Fake> {
+    Router::new()
+        .route(
+            path,
+            post(move |State(fake): State<Fake>, body: Bytes| async move {
+                let mut store = fake.store();
+                let mut entry = parse_object(&body)?;
+
+                if !entry["@id"].is_string() {
+                    entry["@id"] = json!(store.next_id());
+                }
+
+                let mut entry = definition(kind, entry)?;
+                if kind == Kind::Asset {
+                    entry["properties"]["id"] = entry["@id"].clone();
+                }
+
+                ok(store.insert(kind, entry)?)
+            })
+            .put(move |State(fake): State<Fake>, body: Bytes| async move {
+                let entry = definition(kind, parse_object(&body)?)?;
+                fake.store().replace(kind, entry)?;
+
+                no_content()
+            }),
+        )
+        .route(&format!("{}/request", path), query(kind))
+        .route(
+            &format!("{}/:id", path),
+            get_by_id(kind).delete(delete_by_id(kind)),
+        )
+}
+
+/// Validates a definition and fills in the values added by the connector.
+fn definition(kind: Kind, mut entry: Value) -> Result<Value, FakeError> {
+    match kind {
+        Kind::Asset => {
+            require(&entry, "dataAddress")?;
+            entry["dataAddress"]["@type"] = json!("DataAddress");
+            default(&mut entry, "properties", json!({}));
+        }
+        Kind::PolicyDefinition => {
+            require(&entry, "policy")?;
+            if !entry["policy"]["@id"].is_string() {
+                let id = format!("{}-policy", entry["@id"].as_str().unwrap_or_default());
+                entry["policy"]["@id"] = json!(id);
+            }
+        }
+        Kind::ContractDefinition => {
+            require(&entry, "accessPolicyId")?;
+            require(&entry, "contractPolicyId")?;
+            default(&mut entry, "assetsSelector", json!([]));
+        }
+        _ => {}
+    }
+    default(&mut entry, "privateProperties", json!({}));
+
+    Ok(entry)
+}
+
+fn update_by_id(kind: Kind) -> MethodRouter<Fake> {
+    axum::routing::put(
+        move |State(fake): State<Fake>, Path(id): Path<String>, body: Bytes| async move {
+            let mut entry = definition(kind, parse_object(&body)?)?;
+            entry["@id"] = json!(id);
+            fake.store().replace(kind, entry)?;
+
+            no_content()
+        },
+    )
+}
+
+fn get_by_id(kind: Kind) -> MethodRouter<Fake> {
+    get(
+        move |State(fake): State<Fake>, Path(id): Path<String>| async move {
+            ok(fake.store().get(kind, &id)?.clone())
+        },
+    )
+}
+
+fn delete_by_id(kind: Kind) -> MethodRouter<Fake> {
+    axum::routing::delete(
+        move |State(fake): State<Fake>, Path(id): Path<String>| async move {
+            fake.store().remove(kind, &id)?;
+            no_content()
+        },
+    )
+}
+
+fn query(kind: Kind) -> MethodRouter<Fake> {
+    post(move |State(fake): State<Fake>, body: Bytes| async move {
+        let entries = fake.store().query(kind, &parse(&body)?)?;
+
+        Ok::<_, FakeError>(Json(
+            entries.into_iter().map(with_context).collect::<Vec<_>>(),
+        ))
+    })
+}
+
+fn state(kind: Kind, name: &'static str) -> MethodRouter<Fake> {
+    get(
+        move |State(fake): State<Fake>, Path(id): Path<String>| async move {
+            let state = fake.store().get(kind, &id)?["state"].clone();
+            ok(json!({ "@type": name, "state": state }))
+        },
+    )
+}
+
+fn terminate(kind: Kind) -> MethodRouter<Fake> {
+    post(
+        move |State(fake): State<Fake>, Path(id): Path<String>, body: Bytes| async move {
+            let reason = parse(&body)?["reason"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string();
+            let mut store = fake.store();
+
+            store.get(kind, &id)?;
+            workflow::terminate(&mut store, kind, &id, &reason);
+
+            no_content()
+        },
+    )
+}
+
+async fn initiate_negotiation(State(fake): State<Fake>, body: Bytes) -> Reply {
+    let request = parse_object(&body)?;
+    require(&request, "counterPartyAddress")?;
+    require(&request, "policy")?;
+
+    let mut store = fake.store();
+    let id = store.next_id();
+
+    let negotiation = json!({
+        "@id": id,
+        "type": "CONSUMER",
+        "protocol": request["protocol"],
+        "state": "INITIAL",
+        "stateTimestamp": now(),
+        "counterPartyId": request["counterPartyId"].as_str().unwrap_or_default(),
+        "counterPartyAddress": request["counterPartyAddress"],
+        "callbackAddresses": array(&request["callbackAddresses"]),
+        "privateProperties": {},
+    });
+
+    let response = store.insert(Kind::ContractNegotiation, negotiation)?;
+    workflow::negotiate(fake.clone(), id, request["policy"].clone());
+
+    ok(response)
+}
+
+async fn negotiation_agreement(State(fake): State<Fake>, Path(id): Path<String>) -> Reply {
+    let store = fake.store();
+    let negotiation = store.get(Kind::ContractNegotiation, &id)?;
+
+    match negotiation["contractAgreementId"].as_str() {
+        Some(agreement_id) => ok(store.get(Kind::ContractAgreement, agreement_id)?.clone()),
+        None => Err(FakeError::not_found(Kind::ContractAgreement, &id)),
+    }
+}
+
+async fn agreement_negotiation(State(fake): State<Fake>, Path(id): Path<String>) -> Reply {
+    let store = fake.store();
+
+    store
+        .find(Kind::ContractNegotiation, |negotiation| {
+            negotiation["contractAgreementId"] == id.as_str()
+        })
+        .cloned()
+        .ok_or_else(|| FakeError::not_found(Kind::ContractNegotiation, &id))
+        .and_then(ok)
+}
+
+async fn initiate_transfer(State(fake): State<Fake>, body: Bytes) -> Reply {
+    let request = parse_object(&body)?;
+    require(&request, "counterPartyAddress")?;
+    require(&request, "contractId")?;
+    require(&request, "transferType")?;
+
+    let mut store = fake.store();
+    let id = store.next_id();
+
+    let asset_id = store
+        .get(
+            Kind::ContractAgreement,
+            request["contractId"].as_str().unwrap_or_default(),
+        )
+        .map(|agreement| agreement["assetId"].clone())
+        .unwrap_or_else(|_| json!(""));
+
+    let transfer = json!({
+        "@id": id,
+        "type": "CONSUMER",
+        "protocol": request["protocol"],
+        "state": "INITIAL",
+        "stateTimestamp": now(),
+        "assetId": asset_id,
+        "contractId": request["contractId"],
+        "counterPartyAddress": request["counterPartyAddress"],
+        "transferType": request["transferType"],
+        "dataDestination": request["dataDestination"],
+        "callbackAddresses": array(&request["callbackAddresses"]),
+        "privateProperties": {},
+    });
+
+    let response = store.insert(Kind::TransferProcess, transfer)?;
+    workflow::transfer(fake.clone(), id);
+
+    ok(response)
+}
+
+fn set_transfer_state(state: &'static str) -> MethodRouter<Fake> {
+    post(
+        move |State(fake): State<Fake>, Path(id): Path<String>| async move {
+            let mut store = fake.store();
+            let transfer = store.get_mut(Kind::TransferProcess, &id)?;
+
+            transfer["state"] = json!(state);
+            transfer["stateTimestamp"] = json!(now());
+
+            no_content()
+        },
+    )
+}
+
+async fn edr_data_address(State(fake): State<Fake>, Path(id): Path<String>) -> Reply {
+    ok(fake.store().data_address(&id)?.clone())
+}
+
+async fn refresh_edr(State(fake): State<Fake>, Path(id): Path<String>) -> Reply {
+    let mut store = fake.store();
+    store.get(Kind::Edr, &id)?;
+
+    let data_address = workflow::data_address(&mut store, &fake.config);
+    store.set_data_address(&id, data_address.clone());
+
+    ok(data_address)
+}
+
+fn delete_edr() -> MethodRouter<Fake> {
+    axum::routing::delete(
+        |State(fake): State<Fake>, Path(id): Path<String>| async move {
+            let mut store = fake.store();
+            store.remove(Kind::Edr, &id)?;
+            store.remove_data_address(&id);
+
+            no_content()
+        },
+    )
+}
+
+fn parse(body: &Bytes) -> Result<Value, FakeError> {
+    if body.is_empty() {
+        return Ok(Value::Null);
+    }
+
+    let mut value = serde_json::from_slice::<Value>(body)
+        .map_err(|err| FakeError::Validation(err.to_string()))?;
+
+    if let Value::Object(object) = &mut value {
+        object.remove("@context");
+    }
+    Ok(value)
+}
+
+fn parse_object(body: &Bytes) -> Result<Value, FakeError> {
+    match parse(body)? {
+        object @ Value::Object(_) => Ok(object),
+        _ => Err(FakeError::Validation(
+            "Request body must be a JSON object".to_string(),
+        )),
+    }
+}
+
+fn require(entry: &Value, property: &str) -> Result<(), FakeError> {
+    match &entry[property] {
+        Value::Null => Err(FakeError::missing(property)),
+        Value::String(value) if value.is_empty() => Err(FakeError::missing(property)),
+        _ => Ok(()),
+    }
+}
+
+fn default(entry: &mut Value, property: &str, value: Value) {
+    if entry[property].is_null() {
+        entry[property] = value;
+    }
+}
+
+fn array(value: &Value) -> Value {
+    match value {
+        Value::Null => json!([]),
+        Value::Array(_) => value.clone(),
+        value => json!([value]),
+    }
+}
+
+fn with_context(mut value: Value) -> Value {
+    value["@context"] = json!({
+        "@vocab": EDC_NAMESPACE,
+        "edc": EDC_NAMESPACE,
+        "odrl": "http://www.w3.org/ns/odrl/2/",
+    });
+    value
+}
+
+fn ok(value: Value) -> Reply {
+    Ok(Json(with_context(value)).into_response())
+}
+
+fn no_content() -> Reply {
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
@@ -0,0 +1,207 @@
+use std::{
+    collections::HashMap,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde_json::{json, Value};
+
+use super::query;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Kind {
+    Asset,
+    PolicyDefinition,
+    ContractDefinition,
+    ContractNegotiation,
+    ContractAgreement,
+    TransferProcess,
+    Edr,
+}
+
+impl Kind {
+    /// The `@type` of the entries.
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Kind::Asset => "Asset",
+            Kind::PolicyDefinition => "PolicyDefinition",
+            Kind::ContractDefinition => "ContractDefinition",
+            Kind::ContractNegotiation => "ContractNegotiation",
+            Kind::ContractAgreement => "ContractAgreement",
+            Kind::TransferProcess => "TransferProcess",
+            Kind::Edr => "EndpointDataReferenceEntry",
+        }
+    }
+}
+
+/// Errors answered with the same status and body as the management API.
+#[derive(Debug)]
+pub(crate) enum FakeError {
+    NotFound { kind: Kind, id: String },
+    Conflict { kind: Kind, id: String },
+    Validation(String),
+    Unauthorized,
+}
+
+impl FakeError {
+    pub(crate) fn not_found(kind: Kind, id: &str) -> FakeError {
+        FakeError::NotFound {
+            kind,
+            id: id.to_string(),
+        }
+    }
+
+    pub(crate) fn missing(property: &str) -> FakeError {
+        FakeError::Validation(format!(
+            "mandatory value '{}{}' is missing or it is blank",
+            crate::EDC_NAMESPACE,
+            property
+        ))
+    }
+}
+
+impl IntoResponse for FakeError {
+    fn into_response(self) -> Response {
+        let (status, message, kind) = match self {
+            FakeError::NotFound { kind, id } => (
+                StatusCode::NOT_FOUND,
+                format!(
+                    "Object of type {} with ID={} was not found",
+                    kind.name(),
+                    id
+                ),
+                "ObjectNotFound",
+            ),
+            FakeError::Conflict { kind, id } => (
+                StatusCode::CONFLICT,
+                format!(
+                    "Object of type {} with ID={} already exists",
+                    kind.name(),
+                    id
+                ),
+                "ObjectConflict",
+            ),
+            FakeError::Validation(message) => {
+                (StatusCode::BAD_REQUEST, message, "ValidationFailure")
+            }
+            FakeError::Unauthorized => {
+                return (
+                    StatusCode::UNAUTHORIZED,
+                    "Request could not be authenticated",
+                )
+                    .into_response()
+            }
+        };
+
+        (status, Json(json!([{ "message": message, "type": kind }]))).into_response()
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct Store {
+    entries: HashMap<Kind, Vec<Value>>,
+    /// Data addresses of the EDRs by transfer process id.
+    data_addresses: HashMap<String, Value>,
+    sequence: u64,
+}
+
+impl Store {
+    /// A new UUID shaped id.
+    pub(crate) fn next_id(&mut self) -> String {
+        self.sequence += 1;
+        format!("00000000-0000-4000-8000-{:012x}", self.sequence)
+    }
+
+    pub(crate) fn get(&self, kind: Kind, id: &str) -> Result<&Value, FakeError> {
+        self.find(kind, |entry| entry["@id"] == id)
+            .ok_or_else(|| FakeError::not_found(kind, id))
+    }
+
+    pub(crate) fn get_mut(&mut self, kind: Kind, id: &str) -> Result<&mut Value, FakeError> {
+        self.entries
+            .entry(kind)
+            .or_default()
+            .iter_mut()
+            .find(|entry| entry["@id"] == id)
+            .ok_or_else(|| FakeError::not_found(kind, id))
+    }
+
+    pub(crate) fn find(&self, kind: Kind, predicate: impl Fn(&Value) -> bool) -> Option<&Value> {
+        self.entries
+            .get(&kind)
+            .and_then(|entries| entries.iter().find(|entry| predicate(entry)))
+    }
+
+    /// Adds an entry with `@id`, failing if the id is taken.
+    pub(crate) fn insert(&mut self, kind: Kind, mut entry: Value) -> Result<Value, FakeError> {
+        let id = entry["@id"].as_str().unwrap_or_default().to_string();
+
+        if self.get(kind, &id).is_ok() {
+            return Err(FakeError::Conflict { kind, id });
+        }
+
+        entry["@type"] = json!(kind.name());
+        entry["createdAt"] = json!(now());
+
+        self.entries.entry(kind).or_default().push(entry);
+        Ok(json!({ "@type": "IdResponse", "@id": id, "createdAt": now() }))
+    }
+
+    /// Replaces the entry with the same `@id`, keeping its creation date.
+    pub(crate) fn replace(&mut self, kind: Kind, mut entry: Value) -> Result<(), FakeError> {
+        let id = entry["@id"].as_str().unwrap_or_default().to_string();
+        let current = self.get_mut(kind, &id)?;
+
+        entry["@type"] = json!(kind.name());
+        entry["createdAt"] = current["createdAt"].take();
+
+        *current = entry;
+        Ok(())
+    }
+
+    pub(crate) fn remove(&mut self, kind: Kind, id: &str) -> Result<Value, FakeError> {
+        let entries = self.entries.entry(kind).or_default();
+
+        entries
+            .iter()
+            .position(|entry| entry["@id"] == id)
+            .map(|position| entries.remove(position))
+            .ok_or_else(|| FakeError::not_found(kind, id))
+    }
+
+    pub(crate) fn query(&self, kind: Kind, query: &Value) -> Result<Vec<Value>, FakeError> {
+        query::evaluate(
+            self.entries
+                .get(&kind)
+                .map(Vec::as_slice)
+                .unwrap_or_default(),
+            query,
+        )
+    }
+
+    pub(crate) fn data_address(&self, id: &str) -> Result<&Value, FakeError> {
+        self.data_addresses
+            .get(id)
+            .ok_or_else(|| FakeError::not_found(Kind::Edr, id))
+    }
+
+    pub(crate) fn set_data_address(&mut self, id: &str, data_address: Value) {
+        self.data_addresses.insert(id.to_string(), data_address);
+    }
+
+    pub(crate) fn remove_data_address(&mut self, id: &str) {
+        self.data_addresses.remove(id);
+    }
+}
+
+/// Milliseconds since the epoch.
+pub(crate) fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as i64)
+        .unwrap_or_default()
+}
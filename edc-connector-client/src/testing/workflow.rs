@@ -0,0 +1,178 @@
+use serde_json::{json, Value};
+
+use super::{
+    store::{now, Kind, Store},
+    Config, Fake,
+};
+
+const NEGOTIATION_STATES: &[&str] = &["REQUESTING", "REQUESTED", "AGREED", "VERIFIED", "FINALIZED"];
+const TRANSFER_STATES: &[&str] = &["REQUESTING", "REQUESTED", "STARTED"];
+
+const HTTP_DATA_ADDRESS: &str = "https://w3id.org/idsa/v4.1/HTTP";
+
+/// Moves a negotiation to `FINALIZED` and agrees on the offered `policy`.
+pub(crate) fn negotiate(fake: Fake, id: String, policy: Value) {
+    tokio::spawn(async move {
+        let mut current = "INITIAL";
+
+        for next in NEGOTIATION_STATES {
+            tokio::time::sleep(fake.config.state_delay).await;
+
+            let mut store = fake.store();
+            if !transition(&mut store, Kind::ContractNegotiation, &id, current, next) {
+                return;
+            }
+            if *next == "FINALIZED" {
+                agree(&mut store, &fake.config, &id, &policy);
+            }
+            current = next;
+        }
+    });
+}
+
+/// Moves a transfer to `STARTED`, creating the EDR of pull transfers.
+pub(crate) fn transfer(fake: Fake, id: String) {
+    tokio::spawn(async move {
+        let mut current = "INITIAL";
+
+        for next in TRANSFER_STATES {
+            tokio::time::sleep(fake.config.state_delay).await;
+
+            let mut store = fake.store();
+            if !transition(&mut store, Kind::TransferProcess, &id, current, next) {
+                return;
+            }
+            if *next == "REQUESTING" && !has_agreement(&store, &id) {
+                terminate(
+                    &mut store,
+                    Kind::TransferProcess,
+                    &id,
+                    "Contract agreement not found",
+                );
+                return;
+            }
+            if *next == "STARTED" {
+                create_edr(&mut store, &fake.config, &id);
+            }
+            current = next;
+        }
+    });
+}
+
+/// Changes the state of an entry, unless it was moved away from `from` in the
+/// meantime, e.g. terminated.
+fn transition(store: &mut Store, kind: Kind, id: &str, from: &str, to: &str) -> bool {
+    match store.get_mut(kind, id) {
+        Ok(entry) if entry["state"] == from => {
+            entry["state"] = json!(to);
+            entry["stateTimestamp"] = json!(now());
+            true
+        }
+        _ => false,
+    }
+}
+
+pub(crate) fn terminate(store: &mut Store, kind: Kind, id: &str, reason: &str) {
+    if let Ok(entry) = store.get_mut(kind, id) {
+        entry["state"] = json!("TERMINATED");
+        entry["stateTimestamp"] = json!(now());
+        entry["errorDetail"] = json!(reason);
+    }
+}
+
+fn agree(store: &mut Store, config: &Config, negotiation_id: &str, policy: &Value) {
+    let Ok(negotiation) = store.get(Kind::ContractNegotiation, negotiation_id) else {
+        return;
+    };
+
+    let provider_id = negotiation["counterPartyId"].clone();
+    let asset_id = match &policy["target"] {
+        Value::Object(target) => target.get("@id").cloned().unwrap_or_default(),
+        target => target.clone(),
+    };
+    let agreement_id = store.next_id();
+
+    let mut policy = policy.clone();
+    policy["@id"] = json!(agreement_id);
+    policy["@type"] = json!("Agreement");
+    policy["assignee"] = json!(config.participant_id);
+    policy["assigner"] = provider_id.clone();
+
+    let agreement = json!({
+        "@id": agreement_id,
+        "assetId": asset_id,
+        "consumerId": config.participant_id,
+        "providerId": provider_id,
+        "contractSigningDate": now() / 1000,
+        "policy": policy,
+    });
+
+    if store.insert(Kind::ContractAgreement, agreement).is_ok() {
+        if let Ok(negotiation) = store.get_mut(Kind::ContractNegotiation, negotiation_id) {
+            negotiation["contractAgreementId"] = json!(agreement_id);
+        }
+    }
+}
+
+fn has_agreement(store: &Store, transfer_id: &str) -> bool {
+    store
+        .get(Kind::TransferProcess, transfer_id)
+        .ok()
+        .and_then(|transfer| transfer["contractId"].as_str())
+        .map(|contract_id| store.get(Kind::ContractAgreement, contract_id).is_ok())
+        .unwrap_or(false)
+}
+
+fn create_edr(store: &mut Store, config: &Config, transfer_id: &str) {
+    let Ok(transfer) = store.get(Kind::TransferProcess, transfer_id) else {
+        return;
+    };
+
+    if !transfer["transferType"]
+        .as_str()
+        .is_some_and(|kind| kind.ends_with("-PULL"))
+    {
+        return;
+    }
+
+    let contract_id = transfer["contractId"].clone();
+    let asset_id = transfer["assetId"].clone();
+    let provider_id = store
+        .get(
+            Kind::ContractAgreement,
+            contract_id.as_str().unwrap_or_default(),
+        )
+        .map(|agreement| agreement["providerId"].clone())
+        .unwrap_or_default();
+    let negotiation_id = store
+        .find(Kind::ContractNegotiation, |negotiation| {
+            negotiation["contractAgreementId"] == contract_id
+        })
+        .map(|negotiation| negotiation["@id"].clone());
+
+    let entry = json!({
+        "@id": transfer_id,
+        "transferProcessId": transfer_id,
+        "assetId": asset_id,
+        "agreementId": contract_id,
+        "providerId": provider_id,
+        "contractNegotiationId": negotiation_id,
+    });
+
+    if store.insert(Kind::Edr, entry).is_ok() {
+        let data_address = data_address(store, config);
+        store.set_data_address(transfer_id, data_address);
+    }
+}
+
+/// A data address for the public endpoint with a new token.
+pub(crate) fn data_address(store: &mut Store, config: &Config) -> Value {
+    json!({
+        "@type": "DataAddress",
+        "type": HTTP_DATA_ADDRESS,
+        "endpointType": HTTP_DATA_ADDRESS,
+        "endpoint": config.public_endpoint,
+        "authorization": format!("token-{}", store.next_id()),
+        "expiresIn": "300",
+    })
+}
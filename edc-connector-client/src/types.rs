@@ -16,9 +16,10 @@ pub mod policy;
 pub mod properties;
 pub mod query;
 pub mod response;
+pub mod secret;
 pub mod transfer_process;
 
-#[derive(Deserialize, Serialize)]
+#[derive(Deserialize, Serialize, Clone)]
 pub struct Protocol(String);
 
 impl Protocol {
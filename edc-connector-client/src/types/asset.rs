@@ -4,7 +4,7 @@ use crate::error::{BuilderError, ConversionError};
 
 use super::{
     data_address::DataAddress,
-    properties::{FromValue, Properties, PropertyValue, ToValue},
+    properties::{EdcProperties, FromValue, Properties, PropertyValue, ToValue},
 };
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -33,6 +33,19 @@ impl NewAsset {
     pub fn builder() -> NewAssetBuilder {
         NewAssetBuilder::default()
     }
+
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    pub(crate) fn to_asset(&self, id: &str) -> Asset {
+        Asset {
+            id: id.to_string(),
+            properties: self.properties.clone(),
+            private_properties: self.private_properties.clone(),
+            data_address: self.data_address.clone(),
+        }
+    }
 }
 
 impl Asset {
@@ -64,6 +77,22 @@ where {
         &self.private_properties
     }
 
+    /// Reads the properties as a struct deriving `EdcProperties`.
+    pub fn properties_as<T>(&self) -> Result<T, ConversionError>
+    where
+        T: EdcProperties,
+    {
+        T::from_properties(&self.properties)
+    }
+
+    /// Reads the private properties as a struct deriving `EdcProperties`.
+    pub fn private_properties_as<T>(&self) -> Result<T, ConversionError>
+    where
+        T: EdcProperties,
+    {
+        T::from_properties(&self.private_properties)
+    }
+
     pub fn data_address(&self) -> &DataAddress {
         &self.data_address
     }
@@ -99,6 +128,22 @@ impl AssetBuider {
         self
     }
 
+    pub fn properties_from<T>(mut self, properties: T) -> Self
+    where
+        T: EdcProperties,
+    {
+        self.properties.extend(properties.into_properties());
+        self
+    }
+
+    pub fn private_properties_from<T>(mut self, properties: T) -> Self
+    where
+        T: EdcProperties,
+    {
+        self.private_properties.extend(properties.into_properties());
+        self
+    }
+
     pub fn data_address(mut self, data_address: DataAddress) -> Self {
         self.data_address = Some(data_address);
         self
@@ -148,6 +193,22 @@ impl NewAssetBuilder {
         self
     }
 
+    pub fn properties_from<T>(mut self, properties: T) -> Self
+    where
+        T: EdcProperties,
+    {
+        self.properties.extend(properties.into_properties());
+        self
+    }
+
+    pub fn private_properties_from<T>(mut self, properties: T) -> Self
+    where
+        T: EdcProperties,
+    {
+        self.private_properties.extend(properties.into_properties());
+        self
+    }
+
     pub fn data_address(mut self, data_address: DataAddress) -> Self {
         self.data_address = Some(data_address);
         self
@@ -24,6 +24,32 @@ impl CallbackAddress {
     pub fn builder() -> CallbackAddressBuilder {
         CallbackAddressBuilder::default()
     }
+
+    pub fn transactional(&self) -> bool {
+        self.transactional
+    }
+
+    pub fn uri(&self) -> &str {
+        &self.uri
+    }
+
+    pub fn events(&self) -> &[String] {
+        &self.events
+    }
+
+    pub fn auth(&self) -> Option<&CallbackAddressAuth> {
+        self.auth.as_ref()
+    }
+}
+
+impl CallbackAddressAuth {
+    pub fn auth_key(&self) -> &str {
+        &self.auth_key
+    }
+
+    pub fn auth_code_id(&self) -> &str {
+        &self.auth_code_id
+    }
 }
 
 #[derive(Debug, Default)]
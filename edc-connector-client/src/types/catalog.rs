@@ -1,32 +1,134 @@
 use serde::{Deserialize, Serialize};
 use serde_with::{formats::PreferMany, serde_as, OneOrMany};
 
-use crate::BuilderError;
+use crate::{BuilderError, ConversionError};
 
-use super::{policy::Policy, query::Query, Protocol};
+use super::{
+    policy::Policy,
+    properties::{FromValue, Properties},
+    query::Query,
+    Protocol,
+};
 
+/// DCAT catalog of a connector, as returned by the catalog request.
 #[serde_as]
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Catalog {
-    #[serde(rename = "dataset", alias = "dcat:dataset")]
+    #[serde(rename = "@id", skip_serializing_if = "Option::is_none", default)]
+    id: Option<String>,
+    #[serde(
+        rename = "participantId",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    participant_id: Option<String>,
+    /// Older connectors send the participant id in both namespaces.
+    #[serde(
+        rename = "dspace:participantId",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    dspace_participant_id: Option<String>,
+    #[serde(rename = "dataset", alias = "dcat:dataset", default)]
     #[serde_as(deserialize_as = "OneOrMany<_, PreferMany>")]
     datasets: Vec<Dataset>,
+    #[serde(rename = "distribution", alias = "dcat:distribution", default)]
+    #[serde_as(deserialize_as = "OneOrMany<_, PreferMany>")]
+    distributions: Vec<Distribution>,
+    #[serde(rename = "service", alias = "dcat:service", default)]
+    #[serde_as(deserialize_as = "OneOrMany<_, PreferMany>")]
+    services: Vec<DataService>,
+    #[serde(rename = "catalog", alias = "dcat:catalog", default)]
+    #[serde_as(deserialize_as = "OneOrMany<_, PreferMany>")]
+    catalogs: Vec<Catalog>,
+    #[serde(flatten)]
+    properties: Properties,
 }
 
 impl Catalog {
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    /// Id of the participant offering the catalog.
+    pub fn participant_id(&self) -> Option<&str> {
+        self.participant_id
+            .as_deref()
+            .or(self.dspace_participant_id.as_deref())
+    }
+
+    /// The datasets of this catalog, without the ones of the sub-catalogs.
     pub fn datasets(&self) -> &[Dataset] {
         &self.datasets
     }
+
+    pub fn distributions(&self) -> &[Distribution] {
+        &self.distributions
+    }
+
+    /// The sub-catalogs, e.g. the catalogs of the connectors behind a
+    /// catalog server.
+    pub fn catalogs(&self) -> &[Catalog] {
+        &self.catalogs
+    }
+
+    /// This catalog followed by all its sub-catalogs, depth first.
+    pub fn all_catalogs(&self) -> Box<dyn Iterator<Item = &Catalog> + '_> {
+        Box::new(std::iter::once(self).chain(self.catalogs.iter().flat_map(Catalog::all_catalogs)))
+    }
+
+    /// The datasets of this catalog and of all its sub-catalogs.
+    pub fn all_datasets(&self) -> impl Iterator<Item = &Dataset> {
+        self.all_catalogs()
+            .flat_map(|catalog| catalog.datasets.iter())
+    }
+
+    pub(crate) fn into_all_datasets(self) -> Vec<Dataset> {
+        let mut datasets = self.datasets;
+        for catalog in self.catalogs {
+            datasets.extend(catalog.into_all_datasets());
+        }
+        datasets
+    }
+
+    pub fn services(&self) -> &[DataService] {
+        &self.services
+    }
+
+    /// The data service with the given id, e.g. the access service of a
+    /// distribution referenced by id.
+    pub fn service(&self, id: &str) -> Option<&DataService> {
+        self.services
+            .iter()
+            .find(|service| service.id() == Some(id))
+    }
+
+    pub fn property<T>(&self, property: &str) -> Result<Option<T>, ConversionError>
+    where
+        T: FromValue,
+    {
+        self.properties.get(property)
+    }
+
+    /// Properties of the catalog not covered by the typed fields.
+    pub fn properties(&self) -> &Properties {
+        &self.properties
+    }
 }
 
 #[serde_as]
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Dataset {
     #[serde(rename = "@id")]
     id: String,
     #[serde(rename = "hasPolicy", alias = "odrl:hasPolicy")]
     #[serde_as(deserialize_as = "OneOrMany<_, PreferMany>")]
     offers: Vec<Policy>,
+    #[serde(rename = "distribution", alias = "dcat:distribution", default)]
+    #[serde_as(deserialize_as = "OneOrMany<_, PreferMany>")]
+    distributions: Vec<Distribution>,
+    #[serde(flatten)]
+    properties: Properties,
 }
 
 impl Dataset {
@@ -37,9 +139,139 @@ impl Dataset {
     pub fn offers(&self) -> &[Policy] {
         &self.offers
     }
+
+    pub fn distributions(&self) -> &[Distribution] {
+        &self.distributions
+    }
+
+    /// The formats of the distributions, i.e. the transfer types supported
+    /// for the dataset, e.g. `HttpData-PULL`.
+    pub fn transfer_types(&self) -> impl Iterator<Item = &str> {
+        self.distributions.iter().filter_map(Distribution::format)
+    }
+
+    pub fn distribution(&self, format: &str) -> Option<&Distribution> {
+        self.distributions
+            .iter()
+            .find(|distribution| distribution.format() == Some(format))
+    }
+
+    pub fn property<T>(&self, property: &str) -> Result<Option<T>, ConversionError>
+    where
+        T: FromValue,
+    {
+        self.properties.get(property)
+    }
+
+    /// Properties of the dataset, e.g. the public properties of the asset.
+    pub fn properties(&self) -> &Properties {
+        &self.properties
+    }
 }
 
-#[derive(Serialize)]
+/// A way of getting a dataset: the transfer type and the service serving it.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Distribution {
+    #[serde(alias = "dct:format", default, skip_serializing_if = "Option::is_none")]
+    format: Option<Reference>,
+    #[serde(rename = "accessService", alias = "dcat:accessService", default)]
+    access_service: Option<AccessService>,
+}
+
+impl Distribution {
+    /// The transfer type, e.g. `HttpData-PULL`. DCAT does not require it.
+    pub fn format(&self) -> Option<&str> {
+        self.format.as_ref().map(Reference::id)
+    }
+
+    pub fn access_service(&self) -> Option<&AccessService> {
+        self.access_service.as_ref()
+    }
+}
+
+/// The access service of a distribution, either inlined or referenced by the
+/// id of one of the services of the catalog.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum AccessService {
+    Id(String),
+    Service(DataService),
+}
+
+impl AccessService {
+    pub fn id(&self) -> Option<&str> {
+        match self {
+            AccessService::Id(id) => Some(id),
+            AccessService::Service(service) => service.id(),
+        }
+    }
+}
+
+/// Endpoint serving datasets, e.g. the protocol endpoint of a connector.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DataService {
+    #[serde(rename = "@id", skip_serializing_if = "Option::is_none", default)]
+    id: Option<String>,
+    #[serde(
+        rename = "endpointURL",
+        alias = "dcat:endpointURL",
+        alias = "dcat:endpointUrl",
+        alias = "endpointUrl",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    endpoint_url: Option<String>,
+    #[serde(
+        rename = "endpointDescription",
+        alias = "dcat:endpointDescription",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    endpoint_description: Option<String>,
+    #[serde(flatten)]
+    properties: Properties,
+}
+
+impl DataService {
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    pub fn endpoint_url(&self) -> Option<&str> {
+        self.endpoint_url.as_deref()
+    }
+
+    /// E.g. `dspace:connector` for connectors speaking the dataspace protocol.
+    pub fn endpoint_description(&self) -> Option<&str> {
+        self.endpoint_description.as_deref()
+    }
+
+    pub fn properties(&self) -> &Properties {
+        &self.properties
+    }
+}
+
+/// A plain string or a node with an `@id`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(untagged)]
+enum Reference {
+    Simple(String),
+    Id {
+        #[serde(rename = "@id")]
+        id: String,
+    },
+}
+
+impl Reference {
+    fn id(&self) -> &str {
+        match self {
+            Reference::Simple(id) => id,
+            Reference::Id { id } => id,
+        }
+    }
+}
+
+#[derive(Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct CatalogRequest {
     counter_party_address: String,
@@ -52,6 +284,17 @@ impl CatalogRequest {
     pub fn builder() -> CatalogRequestBuilder {
         CatalogRequestBuilder::default()
     }
+
+    pub fn query_spec(&self) -> &Query {
+        &self.query_spec
+    }
+
+    pub(crate) fn with_query_spec(&self, query_spec: Query) -> CatalogRequest {
+        CatalogRequest {
+            query_spec,
+            ..self.clone()
+        }
+    }
 }
 
 #[derive(Default)]
@@ -145,3 +388,154 @@ impl DatasetRequestBuilder {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{AccessService, Catalog};
+
+    #[test]
+    fn should_deserialize_a_dcat_catalog() {
+        let catalog = serde_json::from_value::<Catalog>(json!({
+            "@id": "catalog-id",
+            "@type": "dcat:Catalog",
+            "dcat:dataset": {
+                "@id": "asset-id",
+                "@type": "dcat:Dataset",
+                "odrl:hasPolicy": { "@id": "offer-id", "@type": "odrl:Offer" },
+                "dcat:distribution": [
+                    {
+                        "@type": "dcat:Distribution",
+                        "dct:format": { "@id": "HttpData-PULL" },
+                        "dcat:accessService": {
+                            "@id": "service-id",
+                            "@type": "dcat:DataService",
+                            "dcat:endpointDescription": "dspace:connector",
+                            "dcat:endpointUrl": "http://provider/protocol",
+                            "dct:endpointUrl": "http://provider/protocol"
+                        }
+                    },
+                    {
+                        "@type": "dcat:Distribution",
+                        "dct:format": { "@id": "AmazonS3-PUSH" },
+                        "dcat:accessService": "service-id"
+                    }
+                ],
+                "name": "users",
+                "contenttype": "application/json"
+            },
+            "dcat:service": {
+                "@id": "service-id",
+                "@type": "dcat:DataService",
+                "dcat:endpointURL": "http://provider/protocol"
+            },
+            "dspace:participantId": "provider",
+            "participantId": "provider"
+        }))
+        .unwrap();
+
+        assert_eq!(catalog.id(), Some("catalog-id"));
+        assert_eq!(catalog.participant_id(), Some("provider"));
+        assert_eq!(
+            catalog.service("service-id").unwrap().endpoint_url(),
+            Some("http://provider/protocol")
+        );
+
+        let dataset = &catalog.datasets()[0];
+
+        assert_eq!(
+            dataset.transfer_types().collect::<Vec<_>>(),
+            ["HttpData-PULL", "AmazonS3-PUSH"]
+        );
+        assert_eq!(
+            dataset.property::<String>("name").unwrap(),
+            Some("users".to_string())
+        );
+
+        let Some(AccessService::Service(service)) = dataset.distributions()[0].access_service()
+        else {
+            panic!("expected an inlined access service");
+        };
+
+        assert_eq!(service.endpoint_description(), Some("dspace:connector"));
+        assert_eq!(service.endpoint_url(), Some("http://provider/protocol"));
+        assert_eq!(
+            dataset
+                .distribution("AmazonS3-PUSH")
+                .and_then(|distribution| distribution.access_service())
+                .and_then(AccessService::id),
+            Some("service-id")
+        );
+    }
+
+    #[test]
+    fn should_traverse_nested_catalogs() {
+        let catalog = serde_json::from_value::<Catalog>(json!({
+            "@id": "root",
+            "dcat:dataset": { "@id": "1", "odrl:hasPolicy": [] },
+            "dcat:catalog": [
+                {
+                    "@id": "provider-a",
+                    "dcat:dataset": [{ "@id": "2", "odrl:hasPolicy": [] }],
+                    "dcat:catalog": {
+                        "@id": "provider-b",
+                        "dcat:dataset": { "@id": "3", "odrl:hasPolicy": [] }
+                    }
+                },
+                { "@id": "provider-c", "dcat:dataset": { "@id": "4", "odrl:hasPolicy": [] } }
+            ]
+        }))
+        .unwrap();
+
+        let catalogs = catalog
+            .all_catalogs()
+            .filter_map(Catalog::id)
+            .collect::<Vec<_>>();
+        let datasets = catalog
+            .all_datasets()
+            .map(|dataset| dataset.id())
+            .collect::<Vec<_>>();
+
+        assert_eq!(catalog.catalogs().len(), 2);
+        assert_eq!(catalogs, ["root", "provider-a", "provider-b", "provider-c"]);
+        assert_eq!(datasets, ["1", "2", "3", "4"]);
+        assert_eq!(catalog.into_all_datasets().len(), 4);
+    }
+
+    #[test]
+    fn should_deserialize_a_minimal_catalog() {
+        let catalog = serde_json::from_value::<Catalog>(json!({
+            "dataset": [{ "@id": "asset-id", "hasPolicy": [] }],
+            "dspace:participantId": "provider"
+        }))
+        .unwrap();
+
+        assert_eq!(catalog.participant_id(), Some("provider"));
+        assert!(catalog.services().is_empty());
+        assert_eq!(catalog.datasets()[0].transfer_types().count(), 0);
+    }
+
+    #[test]
+    fn should_skip_distributions_without_format() {
+        let catalog = serde_json::from_value::<Catalog>(json!({
+            "dcat:dataset": {
+                "@id": "asset-id",
+                "odrl:hasPolicy": [],
+                "dcat:distribution": [
+                    { "@type": "dcat:Distribution", "dcat:accessService": "service-id" },
+                    { "@type": "dcat:Distribution", "dct:format": { "@id": "HttpData-PULL" } }
+                ]
+            }
+        }))
+        .unwrap();
+
+        let dataset = &catalog.datasets()[0];
+
+        assert_eq!(dataset.distributions()[0].format(), None);
+        assert_eq!(
+            dataset.transfer_types().collect::<Vec<_>>(),
+            ["HttpData-PULL"]
+        );
+    }
+}
@@ -112,6 +112,7 @@ pub struct NewContractDefinition {
     id: Option<String>,
     access_policy_id: String,
     contract_policy_id: String,
+    #[serde(default)]
     assets_selector: Vec<Criterion>,
     #[serde(default)]
     private_properties: Properties,
@@ -121,6 +122,28 @@ impl NewContractDefinition {
     pub fn builder() -> NewContractDefinitionBuilder {
         NewContractDefinitionBuilder::default()
     }
+
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    pub fn access_policy_id(&self) -> &str {
+        &self.access_policy_id
+    }
+
+    pub fn contract_policy_id(&self) -> &str {
+        &self.contract_policy_id
+    }
+
+    pub(crate) fn to_contract_definition(&self, id: &str) -> ContractDefinition {
+        ContractDefinition {
+            id: id.to_string(),
+            access_policy_id: self.access_policy_id.clone(),
+            contract_policy_id: self.contract_policy_id.clone(),
+            assets_selector: self.assets_selector.clone(),
+            private_properties: self.private_properties.clone(),
+        }
+    }
 }
 
 #[derive(Default)]
@@ -80,7 +80,7 @@ impl ContractRequestBuilder {
 }
 
 #[serde_as]
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ContractNegotiation {
     #[serde(rename = "@id")]
@@ -97,16 +97,17 @@ pub struct ContractNegotiation {
     callback_addresses: Vec<CallbackAddress>,
     #[serde(rename = "type")]
     kind: ContractNegotiationKind,
+    error_detail: Option<String>,
 }
 
-#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ContractNegotiationKind {
     Consumer,
     Provider,
 }
 
-#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ContractNegotiationState {
     Initial,
@@ -195,4 +196,8 @@ impl ContractNegotiation {
     pub fn protocol(&self) -> &str {
         &self.protocol
     }
+
+    pub fn error_detail(&self) -> Option<&String> {
+        self.error_detail.as_ref()
+    }
 }
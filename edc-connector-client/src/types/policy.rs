@@ -1,3 +1,4 @@
+mod evaluation;
 mod odrl;
 
 use serde::{Deserialize, Serialize};
@@ -7,6 +8,12 @@ use crate::{BuilderError, ConversionError};
 
 use super::properties::{FromValue, Properties, PropertyValue, ToValue};
 
+pub(crate) use evaluation::PolicyEvaluationPlanRequest;
+pub use evaluation::{
+    AtomicConstraintStep, ConstraintStep, MultiplicityConstraintStep, PolicyEvaluationPlan,
+    PolicyValidationResult, RuleStep,
+};
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct PolicyDefinition {
@@ -92,6 +99,22 @@ impl NewPolicyDefinition {
     pub fn builder() -> NewPolicyDefinitionBuilder {
         NewPolicyDefinitionBuilder(NewPolicyDefinition::default())
     }
+
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    pub fn policy(&self) -> &Policy {
+        &self.policy
+    }
+
+    pub(crate) fn to_policy_definition(&self, id: &str) -> PolicyDefinition {
+        PolicyDefinition {
+            id: id.to_string(),
+            policy: self.policy.clone(),
+            private_properties: self.private_properties.clone(),
+        }
+    }
 }
 
 #[derive(Default)]
@@ -211,6 +234,17 @@ impl Policy {
     pub fn prohibitions(&self) -> &[Prohibition] {
         &self.prohibitions
     }
+
+    /// Turns a catalog offer into the policy sent in a contract request,
+    /// filling the assigner and the target when the catalog omits them.
+    pub(crate) fn to_offer(&self, assigner: &str, target: &str) -> Policy {
+        Policy {
+            kind: PolicyKind::Offer,
+            assigner: self.assigner.clone().or_else(|| Some(assigner.to_string())),
+            target: self.target.clone().or_else(|| Some(Target::id(target))),
+            ..self.clone()
+        }
+    }
 }
 
 pub struct PolicyBuilder(Policy);
@@ -494,6 +528,22 @@ impl Constraint {
     pub fn xone(constraints: Vec<Constraint>) -> Self {
         Constraint::MultiplicityConstraint(MultiplicityConstraint::Xone(constraints))
     }
+
+    /// The atomic constraints, including the ones nested in `and`, `or` and
+    /// `xone` constraints.
+    pub fn atomic_constraints(&self) -> Vec<&AtomicConstraint> {
+        match self {
+            Constraint::Atomic(atomic) => vec![atomic],
+            Constraint::MultiplicityConstraint(
+                MultiplicityConstraint::Or(constraints)
+                | MultiplicityConstraint::And(constraints)
+                | MultiplicityConstraint::Xone(constraints),
+            ) => constraints
+                .iter()
+                .flat_map(Constraint::atomic_constraints)
+                .collect(),
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
@@ -514,6 +564,13 @@ impl LeftOperand {
     pub fn id(op: &str) -> LeftOperand {
         LeftOperand::Id { id: op.to_string() }
     }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            LeftOperand::Simple(op) => op,
+            LeftOperand::Id { id } => id,
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
@@ -552,6 +609,13 @@ impl Operator {
     pub fn id(op: &str) -> Operator {
         Operator::Id { id: op.to_string() }
     }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            Operator::Simple(op) => op,
+            Operator::Id { id } => id,
+        }
+    }
 }
 
 impl AtomicConstraint {
@@ -574,6 +638,18 @@ impl AtomicConstraint {
             right_operand: PropertyValue(right_operand.into_value()),
         }
     }
+
+    pub fn left_operand(&self) -> &LeftOperand {
+        &self.left_operand
+    }
+
+    pub fn operator(&self) -> &Operator {
+        &self.operator
+    }
+
+    pub fn right_operand(&self) -> &PropertyValue {
+        &self.right_operand
+    }
 }
 
 impl From<&str> for LeftOperand {
@@ -0,0 +1,245 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{formats::PreferMany, serde_as, OneOrMany};
+
+#[serde_as]
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PolicyValidationResult {
+    is_valid: bool,
+    #[serde_as(deserialize_as = "OneOrMany<_, PreferMany>")]
+    #[serde(default)]
+    errors: Vec<String>,
+}
+
+impl PolicyValidationResult {
+    pub fn is_valid(&self) -> bool {
+        self.is_valid
+    }
+
+    pub fn errors(&self) -> &[String] {
+        &self.errors
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PolicyEvaluationPlanRequest {
+    #[serde(rename = "@type")]
+    pub(crate) kind: &'static str,
+    pub(crate) policy_scope: String,
+}
+
+/// How the connector evaluates a policy in a given scope: the validators run
+/// before and after the rules, and the functions bound to every rule and
+/// constraint.
+#[serde_as]
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PolicyEvaluationPlan {
+    #[serde_as(deserialize_as = "OneOrMany<_, PreferMany>")]
+    #[serde(default)]
+    pre_validators: Vec<String>,
+    #[serde_as(deserialize_as = "OneOrMany<_, PreferMany>")]
+    #[serde(default)]
+    post_validators: Vec<String>,
+    #[serde_as(deserialize_as = "OneOrMany<_, PreferMany>")]
+    #[serde(default)]
+    permission_steps: Vec<RuleStep>,
+    #[serde_as(deserialize_as = "OneOrMany<_, PreferMany>")]
+    #[serde(default)]
+    prohibition_steps: Vec<RuleStep>,
+    #[serde_as(deserialize_as = "OneOrMany<_, PreferMany>")]
+    #[serde(default)]
+    obligation_steps: Vec<RuleStep>,
+}
+
+impl PolicyEvaluationPlan {
+    pub fn pre_validators(&self) -> &[String] {
+        &self.pre_validators
+    }
+
+    pub fn post_validators(&self) -> &[String] {
+        &self.post_validators
+    }
+
+    pub fn permission_steps(&self) -> &[RuleStep] {
+        &self.permission_steps
+    }
+
+    pub fn prohibition_steps(&self) -> &[RuleStep] {
+        &self.prohibition_steps
+    }
+
+    pub fn obligation_steps(&self) -> &[RuleStep] {
+        &self.obligation_steps
+    }
+}
+
+/// Evaluation of a permission, prohibition or obligation.
+#[serde_as]
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleStep {
+    #[serde(default)]
+    is_filtered: bool,
+    #[serde_as(deserialize_as = "OneOrMany<_, PreferMany>")]
+    #[serde(default)]
+    filtering_reasons: Vec<String>,
+    #[serde_as(deserialize_as = "OneOrMany<_, PreferMany>")]
+    #[serde(default)]
+    rule_functions: Vec<String>,
+    #[serde_as(deserialize_as = "OneOrMany<_, PreferMany>")]
+    #[serde(default)]
+    constraint_steps: Vec<ConstraintStep>,
+    #[serde_as(deserialize_as = "OneOrMany<_, PreferMany>")]
+    #[serde(default)]
+    duty_steps: Vec<RuleStep>,
+}
+
+impl RuleStep {
+    /// Filtered rules are skipped because they are not bound to the scope.
+    pub fn is_filtered(&self) -> bool {
+        self.is_filtered
+    }
+
+    pub fn filtering_reasons(&self) -> &[String] {
+        &self.filtering_reasons
+    }
+
+    pub fn rule_functions(&self) -> &[String] {
+        &self.rule_functions
+    }
+
+    pub fn constraint_steps(&self) -> &[ConstraintStep] {
+        &self.constraint_steps
+    }
+
+    /// Duties of a permission step.
+    pub fn duty_steps(&self) -> &[RuleStep] {
+        &self.duty_steps
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(tag = "@type")]
+pub enum ConstraintStep {
+    #[serde(rename = "AtomicConstraintStep")]
+    Atomic(AtomicConstraintStep),
+    #[serde(rename = "AndConstraintStep")]
+    And(MultiplicityConstraintStep),
+    #[serde(rename = "OrConstraintStep")]
+    Or(MultiplicityConstraintStep),
+    #[serde(rename = "XoneConstraintStep")]
+    Xone(MultiplicityConstraintStep),
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AtomicConstraintStep {
+    #[serde(default)]
+    is_filtered: bool,
+    #[serde_as(deserialize_as = "OneOrMany<_, PreferMany>")]
+    #[serde(default)]
+    filtering_reasons: Vec<String>,
+    function_name: Option<String>,
+    #[serde_as(deserialize_as = "OneOrMany<_, PreferMany>")]
+    #[serde(default)]
+    function_params: Vec<String>,
+}
+
+impl AtomicConstraintStep {
+    pub fn is_filtered(&self) -> bool {
+        self.is_filtered
+    }
+
+    pub fn filtering_reasons(&self) -> &[String] {
+        &self.filtering_reasons
+    }
+
+    /// Function bound to the left operand, if any.
+    pub fn function_name(&self) -> Option<&String> {
+        self.function_name.as_ref()
+    }
+
+    /// Left operand, operator and right operand the function is invoked with.
+    pub fn function_params(&self) -> &[String] {
+        &self.function_params
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MultiplicityConstraintStep {
+    #[serde_as(deserialize_as = "OneOrMany<_, PreferMany>")]
+    #[serde(default)]
+    constraint_steps: Vec<ConstraintStep>,
+}
+
+impl MultiplicityConstraintStep {
+    pub fn constraint_steps(&self) -> &[ConstraintStep] {
+        &self.constraint_steps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{ConstraintStep, PolicyEvaluationPlan};
+
+    #[test]
+    fn should_deserialize_an_evaluation_plan() {
+        let json = json!({
+            "@type": "PolicyEvaluationPlan",
+            "preValidators": "DcpScopeExtractorFunction",
+            "permissionSteps": {
+                "@type": "PermissionStep",
+                "isFiltered": false,
+                "filteringReasons": [],
+                "ruleFunctions": [],
+                "constraintSteps": {
+                    "@type": "OrConstraintStep",
+                    "constraintSteps": [{
+                        "@type": "AtomicConstraintStep",
+                        "isFiltered": false,
+                        "filteringReasons": [],
+                        "functionName": "BusinessPartnerNumberPermissionFunction",
+                        "functionParams": ["'BusinessPartnerNumber'", "EQ", "'BPNL000000000001'"]
+                    }, {
+                        "@type": "AtomicConstraintStep",
+                        "isFiltered": true,
+                        "filteringReasons": ["leftOperand 'foo' is not bound to scope 'catalog'"],
+                        "functionParams": ["'foo'", "EQ", "'bar'"]
+                    }]
+                },
+                "dutySteps": []
+            },
+            "prohibitionSteps": [],
+            "obligationSteps": [],
+            "postValidators": []
+        });
+
+        let plan = serde_json::from_value::<PolicyEvaluationPlan>(json).unwrap();
+
+        assert_eq!(plan.pre_validators(), ["DcpScopeExtractorFunction"]);
+        assert_eq!(plan.permission_steps().len(), 1);
+
+        let ConstraintStep::Or(or) = &plan.permission_steps()[0].constraint_steps()[0] else {
+            panic!("Expected an or constraint step");
+        };
+
+        match or.constraint_steps() {
+            [ConstraintStep::Atomic(bound), ConstraintStep::Atomic(unbound)] => {
+                assert_eq!(
+                    bound.function_name().unwrap(),
+                    "BusinessPartnerNumberPermissionFunction"
+                );
+                assert!(unbound.is_filtered());
+                assert_eq!(unbound.function_name(), None);
+            }
+            other => panic!("Unexpected constraint steps {:?}", other),
+        }
+    }
+}
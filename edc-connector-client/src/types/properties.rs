@@ -1,4 +1,5 @@
 mod conversion;
+mod typed;
 
 use std::collections::HashMap;
 
@@ -7,7 +8,14 @@ use serde_json::Value;
 
 use crate::error::ConversionError;
 
-pub use self::conversion::{FromValue, ToValue};
+#[cfg(feature = "crawler")]
+pub(crate) use self::conversion::literal;
+pub use self::conversion::{FromValue, Json, ToValue};
+#[doc(hidden)]
+pub use self::typed::__private;
+pub use self::typed::EdcProperties;
+#[cfg(feature = "derive")]
+pub use edc_connector_client_derive::EdcProperties;
 
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
 pub struct Properties(HashMap<String, PropertyValue>);
@@ -24,6 +32,7 @@ impl Properties {
             .get(property)
             .map(PropertyValue::try_from)
             .transpose()
+            .map_err(|err| err.at_key(property))
     }
 
     pub fn get_raw(&self, property: &str) -> Option<&PropertyValue> {
@@ -42,6 +51,10 @@ impl Properties {
             .insert(property.to_string(), PropertyValue(value.into_value()));
     }
 
+    pub(crate) fn extend(&mut self, properties: Properties) {
+        self.0.extend(properties.0);
+    }
+
     pub fn contains(&self, property: &str) -> bool {
         self.0.contains_key(property)
     }
@@ -1,4 +1,7 @@
-use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{Map, Value};
 
 use crate::error::ConversionError;
 
@@ -7,11 +10,148 @@ pub trait FromValue: Sized {
     fn try_from(v: &Value) -> Result<Self, ConversionError>;
 }
 
+/// Adapter reading any `serde` type from a property value, and writing it with
+/// [`Json::to_value`].
+///
+/// ```rust
+/// use edc_connector_client::types::{asset::NewAsset, data_address::DataAddress, properties::Json};
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Dimensions {
+///     width: u32,
+///     height: u32,
+/// }
+///
+/// let asset = NewAsset::builder()
+///     .property(
+///         "dimensions",
+///         Json(Dimensions { width: 640, height: 480 }).to_value().unwrap(),
+///     )
+///     .data_address(DataAddress::builder().kind("HttpData").build().unwrap())
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Json<T>(pub T);
+
+impl<T> Json<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Json<T>
+where
+    T: Serialize,
+{
+    /// Serializes the value, failing on values JSON cannot represent, like
+    /// maps with non-string keys.
+    pub fn to_value(&self) -> Result<Value, serde_json::Error> {
+        serde_json::to_value(&self.0)
+    }
+}
+
+/// Unwraps JSON-LD value objects like `{ "@value": "42", "@type": "xsd:integer" }`.
+pub(crate) fn literal(v: &Value) -> &Value {
+    match v {
+        Value::Object(object) => object.get("@value").unwrap_or(v),
+        _ => v,
+    }
+}
+
 impl FromValue for String {
     fn try_from(v: &Value) -> Result<Self, ConversionError> {
-        match v {
+        match literal(v) {
             Value::String(s) => Ok(s.clone()),
-            _ => Err(ConversionError {}),
+            other => Err(ConversionError::new("string", other)),
+        }
+    }
+}
+
+impl FromValue for bool {
+    fn try_from(v: &Value) -> Result<Self, ConversionError> {
+        let v = literal(v);
+        match v {
+            Value::Bool(b) => Ok(*b),
+            Value::String(s) => s.parse().map_err(|_| ConversionError::new("boolean", v)),
+            _ => Err(ConversionError::new("boolean", v)),
+        }
+    }
+}
+
+macro_rules! integer_value {
+    ($($ty:ty),*) => {
+        $(
+            impl FromValue for $ty {
+                fn try_from(v: &Value) -> Result<Self, ConversionError> {
+                    let v = literal(v);
+                    match v {
+                        Value::Number(n) => n
+                            .as_i64()
+                            .and_then(|n| <$ty as TryFrom<i64>>::try_from(n).ok())
+                            .or_else(|| {
+                                n.as_u64()
+                                    .and_then(|n| <$ty as TryFrom<u64>>::try_from(n).ok())
+                            }),
+                        Value::String(s) => s.parse().ok(),
+                        _ => None,
+                    }
+                    .ok_or_else(|| ConversionError::new(stringify!($ty), v))
+                }
+            }
+
+            impl ToValue for $ty {
+                fn into_value(self) -> Value {
+                    Value::from(self)
+                }
+            }
+        )*
+    };
+}
+
+integer_value!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+macro_rules! float_value {
+    ($($ty:ty),*) => {
+        $(
+            impl FromValue for $ty {
+                fn try_from(v: &Value) -> Result<Self, ConversionError> {
+                    let v = literal(v);
+                    match v {
+                        Value::Number(n) => n.as_f64().map(|n| n as $ty),
+                        Value::String(s) => s.parse().ok(),
+                        _ => None,
+                    }
+                    .ok_or_else(|| ConversionError::new(stringify!($ty), v))
+                }
+            }
+
+            impl ToValue for $ty {
+                fn into_value(self) -> Value {
+                    Value::from(self)
+                }
+            }
+        )*
+    };
+}
+
+float_value!(f32, f64);
+
+impl FromValue for Value {
+    fn try_from(v: &Value) -> Result<Self, ConversionError> {
+        Ok(v.clone())
+    }
+}
+
+impl<T> FromValue for Option<T>
+where
+    T: FromValue,
+{
+    fn try_from(v: &Value) -> Result<Self, ConversionError> {
+        match v {
+            Value::Null => Ok(None),
+            _ => T::try_from(v).map(Some),
         }
     }
 }
@@ -24,13 +164,88 @@ where
         match v {
             Value::Array(arr) => arr
                 .iter()
-                .map(T::try_from)
+                .enumerate()
+                .map(|(index, value)| T::try_from(value).map_err(|err| err.at_index(index)))
                 .collect::<Result<Vec<_>, ConversionError>>(),
             _ => T::try_from(v).map(|value| vec![value]),
         }
     }
 }
 
+impl<T> FromValue for HashMap<String, T>
+where
+    T: FromValue,
+{
+    fn try_from(v: &Value) -> Result<Self, ConversionError> {
+        match v {
+            Value::Object(object) => object
+                .iter()
+                .map(|(key, value)| {
+                    T::try_from(value)
+                        .map(|value| (key.clone(), value))
+                        .map_err(|err| err.at_key(key))
+                })
+                .collect(),
+            other => Err(ConversionError::new("object", other)),
+        }
+    }
+}
+
+impl<T> FromValue for BTreeMap<String, T>
+where
+    T: FromValue,
+{
+    fn try_from(v: &Value) -> Result<Self, ConversionError> {
+        match v {
+            Value::Object(object) => object
+                .iter()
+                .map(|(key, value)| {
+                    T::try_from(value)
+                        .map(|value| (key.clone(), value))
+                        .map_err(|err| err.at_key(key))
+                })
+                .collect(),
+            other => Err(ConversionError::new("object", other)),
+        }
+    }
+}
+
+impl<T> FromValue for Json<T>
+where
+    T: DeserializeOwned,
+{
+    fn try_from(v: &Value) -> Result<Self, ConversionError> {
+        T::deserialize(v)
+            .map(Json)
+            .map_err(|_| ConversionError::new(std::any::type_name::<T>(), v))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl FromValue for chrono::DateTime<chrono::Utc> {
+    fn try_from(v: &Value) -> Result<Self, ConversionError> {
+        <chrono::DateTime<chrono::FixedOffset> as FromValue>::try_from(v).map(|date| date.to_utc())
+    }
+}
+
+/// Parses RFC 3339 strings and milliseconds since the epoch, the format of
+/// the timestamps of the connector.
+#[cfg(feature = "chrono")]
+impl FromValue for chrono::DateTime<chrono::FixedOffset> {
+    fn try_from(v: &Value) -> Result<Self, ConversionError> {
+        let v = literal(v);
+        match v {
+            Value::String(s) => chrono::DateTime::parse_from_rfc3339(s).ok(),
+            Value::Number(n) => n
+                .as_i64()
+                .and_then(chrono::DateTime::from_timestamp_millis)
+                .map(|date| date.fixed_offset()),
+            _ => None,
+        }
+        .ok_or_else(|| ConversionError::new("date-time", v))
+    }
+}
+
 #[doc(hidden)]
 pub trait ToValue: Sized {
     fn into_value(self) -> Value;
@@ -54,6 +269,27 @@ impl ToValue for String {
     }
 }
 
+impl ToValue for bool {
+    fn into_value(self) -> Value {
+        Value::Bool(self)
+    }
+}
+
+impl ToValue for Value {
+    fn into_value(self) -> Value {
+        self
+    }
+}
+
+impl<T> ToValue for Option<T>
+where
+    T: ToValue,
+{
+    fn into_value(self) -> Value {
+        self.map(T::into_value).unwrap_or(Value::Null)
+    }
+}
+
 impl<T> ToValue for Vec<T>
 where
     T: ToValue,
@@ -64,3 +300,149 @@ where
         Value::Array(values)
     }
 }
+
+impl<T> ToValue for HashMap<String, T>
+where
+    T: ToValue,
+{
+    fn into_value(self) -> Value {
+        Value::Object(
+            self.into_iter()
+                .map(|(key, value)| (key, value.into_value()))
+                .collect::<Map<_, _>>(),
+        )
+    }
+}
+
+impl<T> ToValue for BTreeMap<String, T>
+where
+    T: ToValue,
+{
+    fn into_value(self) -> Value {
+        Value::Object(
+            self.into_iter()
+                .map(|(key, value)| (key, value.into_value()))
+                .collect::<Map<_, _>>(),
+        )
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz> ToValue for chrono::DateTime<Tz>
+where
+    Tz: chrono::TimeZone,
+    Tz::Offset: std::fmt::Display,
+{
+    fn into_value(self) -> Value {
+        Value::String(self.to_rfc3339())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    use super::{FromValue, Json, ToValue};
+    use crate::ValueKind;
+
+    #[test]
+    fn should_convert_numbers_and_booleans() {
+        assert_eq!(<u32 as FromValue>::try_from(&json!(42)), Ok(42));
+        assert_eq!(<i64 as FromValue>::try_from(&json!("-42")), Ok(-42));
+        assert_eq!(<f64 as FromValue>::try_from(&json!(1.5)), Ok(1.5));
+        assert_eq!(<bool as FromValue>::try_from(&json!("true")), Ok(true));
+        assert!(<u8 as FromValue>::try_from(&json!(300)).is_err());
+        assert!(<u32 as FromValue>::try_from(&json!(-1)).is_err());
+
+        assert_eq!(42u32.into_value(), json!(42));
+        assert_eq!(false.into_value(), json!(false));
+    }
+
+    #[test]
+    fn should_report_the_path_of_nested_values() {
+        let value = json!({ "sizes": [640, "large"] });
+
+        let error = <HashMap<String, Vec<u32>> as FromValue>::try_from(&value).unwrap_err();
+
+        assert_eq!(error.path(), "sizes[1]");
+        assert_eq!(error.expected(), "u32");
+        assert_eq!(error.actual(), ValueKind::String);
+    }
+
+    #[test]
+    fn should_unwrap_json_ld_literals() {
+        let value = json!({ "@value": "42", "@type": "xsd:integer" });
+
+        assert_eq!(<u64 as FromValue>::try_from(&value), Ok(42));
+        assert_eq!(
+            <String as FromValue>::try_from(&value),
+            Ok("42".to_string())
+        );
+    }
+
+    #[test]
+    fn should_convert_maps_and_options() {
+        let value = json!({ "width": 640, "height": 480 });
+
+        let map = <HashMap<String, u32> as FromValue>::try_from(&value).unwrap();
+
+        assert_eq!(map.get("width"), Some(&640));
+        assert_eq!(map.into_value(), value);
+
+        assert_eq!(<Option<u32> as FromValue>::try_from(&json!(null)), Ok(None));
+        assert_eq!(Option::<u32>::None.into_value(), json!(null));
+    }
+
+    #[test]
+    fn should_convert_serde_types() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Dimensions {
+            width: u32,
+            height: u32,
+        }
+
+        let value = Json(Dimensions {
+            width: 640,
+            height: 480,
+        })
+        .to_value()
+        .unwrap();
+
+        assert_eq!(value, json!({ "width": 640, "height": 480 }));
+        assert_eq!(
+            <Json<Dimensions> as FromValue>::try_from(&value),
+            Ok(Json(Dimensions {
+                width: 640,
+                height: 480
+            }))
+        );
+    }
+
+    #[test]
+    fn should_fail_to_serialize_unrepresentable_values() {
+        let value = Json(HashMap::from([((1, 2), "pixel")]));
+
+        assert!(value.to_value().is_err());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn should_convert_date_times() {
+        use chrono::{DateTime, TimeZone, Utc};
+
+        let date = Utc.with_ymd_and_hms(2024, 5, 1, 12, 30, 0).unwrap();
+
+        assert_eq!(date.into_value(), json!("2024-05-01T12:30:00+00:00"));
+        assert_eq!(
+            <DateTime<Utc> as FromValue>::try_from(&json!("2024-05-01T14:30:00+02:00")),
+            Ok(date)
+        );
+        assert_eq!(
+            <DateTime<Utc> as FromValue>::try_from(&json!(date.timestamp_millis())),
+            Ok(date)
+        );
+    }
+}
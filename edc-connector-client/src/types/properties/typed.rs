@@ -0,0 +1,113 @@
+use std::borrow::Cow;
+
+use crate::{error::ConversionError, EDC_NAMESPACE};
+
+use super::Properties;
+
+/// Struct mapped to and from [`Properties`], usually derived with
+/// `#[derive(EdcProperties)]` from the `derive` feature.
+///
+/// ```rust
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use edc_connector_client::{types::properties::EdcProperties, EDC_NAMESPACE};
+///
+/// #[derive(EdcProperties)]
+/// #[edc(namespace = EDC_NAMESPACE)]
+/// struct Metadata {
+///     name: String,
+///     #[edc(rename = "contenttype")]
+///     content_type: Option<String>,
+///     #[edc(namespace = "http://purl.org/dc/terms/")]
+///     issued: u64,
+/// }
+/// # }
+/// ```
+pub trait EdcProperties: Sized {
+    fn from_properties(properties: &Properties) -> Result<Self, ConversionError>;
+
+    fn into_properties(self) -> Properties;
+}
+
+/// Helpers invoked by the code generated by `#[derive(EdcProperties)]`.
+#[doc(hidden)]
+pub mod __private {
+    use crate::{
+        error::ConversionError,
+        types::properties::{FromValue, Properties, ToValue},
+        EDC_NAMESPACE,
+    };
+
+    use super::key;
+
+    pub fn get<T>(
+        properties: &Properties,
+        namespace: Option<&str>,
+        property: &str,
+        field: &str,
+    ) -> Result<Option<T>, ConversionError>
+    where
+        T: FromValue,
+    {
+        let key = key(namespace, property);
+        let value = properties.get_raw(&key).or_else(|| {
+            // Properties built outside of the client may not be compacted.
+            match namespace {
+                Some(EDC_NAMESPACE) => properties.get_raw(&format!("{EDC_NAMESPACE}{property}")),
+                _ => None,
+            }
+        });
+
+        value
+            .map(|value| value.try_from::<T>())
+            .transpose()
+            .map_err(|err| err.at_key(&key).with_field(field))
+    }
+
+    pub fn required<T>(
+        properties: &Properties,
+        namespace: Option<&str>,
+        property: &str,
+        field: &str,
+    ) -> Result<T, ConversionError>
+    where
+        T: FromValue,
+    {
+        get(properties, namespace, property, field)?.ok_or_else(|| {
+            ConversionError::missing_field(
+                field,
+                &key(namespace, property),
+                std::any::type_name::<T>(),
+            )
+        })
+    }
+
+    pub fn set<T>(properties: &mut Properties, namespace: Option<&str>, property: &str, value: T)
+    where
+        T: ToValue,
+    {
+        properties.set(&key(namespace, property), value);
+    }
+
+    pub fn set_optional<T>(
+        properties: &mut Properties,
+        namespace: Option<&str>,
+        property: &str,
+        value: Option<T>,
+    ) where
+        T: ToValue,
+    {
+        if let Some(value) = value {
+            set(properties, namespace, property, value);
+        }
+    }
+}
+
+/// Properties in the EDC namespace are compacted against the `@vocab` of the
+/// requests, all the others are sent as full IRIs.
+fn key<'a>(namespace: Option<&str>, property: &'a str) -> Cow<'a, str> {
+    match namespace {
+        Some(namespace) if namespace != EDC_NAMESPACE => format!("{namespace}{property}").into(),
+        _ => property.into(),
+    }
+}
@@ -0,0 +1,115 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::BuilderError;
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Secret {
+    #[serde(rename = "@id")]
+    id: String,
+    value: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewSecret {
+    #[serde(rename = "@id")]
+    id: Option<String>,
+    value: String,
+}
+
+impl Secret {
+    pub fn builder() -> SecretBuilder {
+        SecretBuilder::default()
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl NewSecret {
+    pub fn builder() -> NewSecretBuilder {
+        NewSecretBuilder::default()
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Secret")
+            .field("id", &self.id)
+            .field("value", &"<redacted>")
+            .finish()
+    }
+}
+
+impl fmt::Debug for NewSecret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NewSecret")
+            .field("id", &self.id)
+            .field("value", &"<redacted>")
+            .finish()
+    }
+}
+
+#[derive(Default)]
+pub struct SecretBuilder {
+    id: Option<String>,
+    value: Option<String>,
+}
+
+impl SecretBuilder {
+    pub fn id(mut self, id: &str) -> Self {
+        self.id = Some(id.to_string());
+        self
+    }
+
+    pub fn value(mut self, value: &str) -> Self {
+        self.value = Some(value.to_string());
+        self
+    }
+
+    pub fn build(self) -> Result<Secret, BuilderError> {
+        Ok(Secret {
+            id: self
+                .id
+                .ok_or_else(|| BuilderError::missing_property("id"))?,
+            value: self
+                .value
+                .ok_or_else(|| BuilderError::missing_property("value"))?,
+        })
+    }
+}
+
+#[derive(Default)]
+pub struct NewSecretBuilder {
+    id: Option<String>,
+    value: Option<String>,
+}
+
+impl NewSecretBuilder {
+    pub fn id(mut self, id: &str) -> Self {
+        self.id = Some(id.to_string());
+        self
+    }
+
+    pub fn value(mut self, value: &str) -> Self {
+        self.value = Some(value.to_string());
+        self
+    }
+
+    pub fn build(self) -> Result<NewSecret, BuilderError> {
+        Ok(NewSecret {
+            id: self.id,
+            value: self
+                .value
+                .ok_or_else(|| BuilderError::missing_property("value"))?,
+        })
+    }
+}
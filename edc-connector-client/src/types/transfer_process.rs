@@ -87,7 +87,7 @@ impl TransferRequestBuilder {
 }
 
 #[serde_as]
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct TransferProcess {
     #[serde(rename = "@id")]
@@ -105,6 +105,7 @@ pub struct TransferProcess {
     transfer_type: String,
     #[serde(rename = "type")]
     kind: TransferProcessKind,
+    error_detail: Option<String>,
 }
 
 impl TransferProcess {
@@ -158,9 +159,13 @@ impl TransferProcess {
     pub fn callback_addresses(&self) -> &[CallbackAddress] {
         &self.callback_addresses
     }
+
+    pub fn error_detail(&self) -> Option<&String> {
+        self.error_detail.as_ref()
+    }
 }
 
-#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum TransferProcessState {
     Initial,
@@ -186,7 +191,7 @@ pub enum TransferProcessState {
     Other(String),
 }
 
-#[derive(Debug, Deserialize, Clone, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum TransferProcessKind {
     Consumer,
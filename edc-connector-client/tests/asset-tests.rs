@@ -107,7 +107,7 @@ mod delete {
 mod get {
     use edc_connector_client::{
         types::{asset::NewAsset, data_address::DataAddress},
-        ConversionError, Error, ManagementApiError, ManagementApiErrorDetailKind,
+        ConversionError, Error, ManagementApiError, ManagementApiErrorDetailKind, ValueKind,
     };
     use reqwest::StatusCode;
     use uuid::Uuid;
@@ -165,6 +165,12 @@ mod get {
             asset.property::<String>("foo_arr"),
             Err(ConversionError { .. })
         ));
+
+        let error = asset.property::<String>("foo_arr").unwrap_err();
+
+        assert_eq!(error.path(), "foo_arr");
+        assert_eq!(error.expected(), "string");
+        assert_eq!(error.actual(), ValueKind::Array);
     }
     #[tokio::test]
     async fn should_fail_to_get_an_asset_when_not_existing() {
@@ -289,7 +295,7 @@ mod query {
         assert_eq!(
             "bar",
             assets
-                .get(0)
+                .first()
                 .unwrap()
                 .property::<String>("foo")
                 .unwrap()
@@ -333,7 +339,7 @@ mod query {
 
         assert_eq!(
             Ok(Some("baz".to_string())),
-            assets.get(0).unwrap().property::<String>("foo")
+            assets.first().unwrap().property::<String>("foo")
         )
     }
 
@@ -374,7 +380,7 @@ mod query {
 
         assert_eq!(
             Ok(Some("bar".to_string())),
-            assets.get(0).unwrap().property::<String>("foo")
+            assets.first().unwrap().property::<String>("foo")
         );
 
         let query = Query::builder()
@@ -390,7 +396,7 @@ mod query {
 
         assert_eq!(
             Ok(Some("baz".to_string())),
-            assets.get(0).unwrap().property::<String>("foo")
+            assets.first().unwrap().property::<String>("foo")
         )
     }
 }
@@ -0,0 +1,163 @@
+#![cfg(feature = "callbacks")]
+
+use std::sync::{Arc, Mutex};
+
+use edc_connector_client::callbacks::{
+    CallbackReceiver, Event, EventEnvelope, EventHandler, HandlerError,
+};
+use reqwest::StatusCode;
+use serde_json::json;
+
+#[derive(Clone, Default)]
+struct Recorder(Arc<Mutex<Vec<EventEnvelope>>>);
+
+#[async_trait::async_trait]
+impl EventHandler for Recorder {
+    async fn handle(&self, envelope: EventEnvelope) -> Result<(), HandlerError> {
+        self.0.lock().unwrap().push(envelope);
+        Ok(())
+    }
+}
+
+struct Failing;
+
+#[async_trait::async_trait]
+impl EventHandler for Failing {
+    async fn handle(&self, _envelope: EventEnvelope) -> Result<(), HandlerError> {
+        Err("boom".into())
+    }
+}
+
+async fn serve(receiver: CallbackReceiver) -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        axum::serve(listener, receiver.into_router()).await.unwrap();
+    });
+
+    format!("http://{}/", address)
+}
+
+fn transfer_started() -> serde_json::Value {
+    json!({
+        "id": "event-id",
+        "at": 1700000000000i64,
+        "type": "TransferProcessStarted",
+        "payload": {
+            "transferProcessId": "transfer-id",
+            "assetId": "asset-id",
+            "contractId": "contract-id",
+            "type": "CONSUMER",
+            "callbackAddresses": [{
+                "transactional": false,
+                "uri": "http://consumer/callbacks",
+                "events": ["transfer.process.started"],
+                "authKey": "X-Api-Key",
+                "authCodeId": "callback-secret"
+            }]
+        }
+    })
+}
+
+#[tokio::test]
+async fn should_dispatch_events_to_the_handler() {
+    let recorder = Recorder::default();
+    let url = serve(
+        CallbackReceiver::builder()
+            .handler(recorder.clone())
+            .auth("X-Api-Key", "secret")
+            .build()
+            .unwrap(),
+    )
+    .await;
+
+    let response = reqwest::Client::new()
+        .post(&url)
+        .header("X-Api-Key", "secret")
+        .json(&transfer_started())
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let events = recorder.0.lock().unwrap();
+    assert_eq!(events.len(), 1);
+
+    match events[0].event() {
+        Event::TransferProcessStarted(event) => {
+            assert_eq!(event.transfer_process_id(), "transfer-id");
+            assert_eq!(event.contract_id().unwrap(), "contract-id");
+            assert_eq!(
+                event.callback_addresses()[0].auth().unwrap().auth_key(),
+                "X-Api-Key"
+            );
+        }
+        other => panic!("Unexpected event {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn should_reject_unauthenticated_deliveries() {
+    let recorder = Recorder::default();
+    let url = serve(
+        CallbackReceiver::builder()
+            .handler(recorder.clone())
+            .auth("X-Api-Key", "secret")
+            .build()
+            .unwrap(),
+    )
+    .await;
+
+    let response = reqwest::Client::new()
+        .post(&url)
+        .header("X-Api-Key", "wrong")
+        .json(&transfer_started())
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert!(recorder.0.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn should_reject_malformed_deliveries() {
+    let url = serve(
+        CallbackReceiver::builder()
+            .handler(Recorder::default())
+            .build()
+            .unwrap(),
+    )
+    .await;
+
+    let response = reqwest::Client::new()
+        .post(&url)
+        .json(&json!({ "foo": "bar" }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn should_report_handler_failures() {
+    let url = serve(
+        CallbackReceiver::builder()
+            .handler(Failing)
+            .build()
+            .unwrap(),
+    )
+    .await;
+
+    let response = reqwest::Client::new()
+        .post(&url)
+        .json(&transfer_started())
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+}
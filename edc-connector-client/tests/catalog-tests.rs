@@ -6,7 +6,9 @@ mod catalog {
         EDC_NAMESPACE,
     };
 
-    use crate::common::{seed, setup_consumer_client, setup_provider_client, PROVIDER_PROTOCOL};
+    use crate::common::{
+        seed, setup_consumer_client, setup_provider_client, PROVIDER_ID, PROVIDER_PROTOCOL,
+    };
 
     #[tokio::test]
     async fn should_get_the_catalog() {
@@ -30,6 +32,11 @@ mod catalog {
         let dataset = response.datasets().iter().find(|ds| ds.id() == asset_id);
 
         assert!(dataset.is_some());
+        assert_eq!(response.participant_id(), Some(PROVIDER_ID));
+        assert!(dataset
+            .unwrap()
+            .transfer_types()
+            .any(|transfer_type| transfer_type == "HttpData-PULL"));
     }
 }
 
@@ -0,0 +1,183 @@
+use std::time::Duration;
+
+use edc_connector_client::{
+    types::{asset::NewAsset, catalog::Dataset, data_address::DataAddress},
+    Auth, EdcConnectorClient, OAuth2ClientCredentials, RetryPolicy, WaitOptions,
+};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+pub fn setup_client(server: &MockServer) -> EdcConnectorClient {
+    EdcConnectorClient::builder()
+        .management_url(format!("{}/management", server.uri()))
+        .build()
+        .unwrap()
+}
+
+pub fn setup_api_key_client(server: &MockServer) -> EdcConnectorClient {
+    EdcConnectorClient::builder()
+        .management_url(format!("{}/management", server.uri()))
+        .with_auth(Auth::api_token("123456"))
+        .build()
+        .unwrap()
+}
+
+pub fn setup_oauth2_client(server: &MockServer) -> EdcConnectorClient {
+    let credentials = OAuth2ClientCredentials::builder()
+        .token_url(format!("{}/token", server.uri()))
+        .client_id("client")
+        .client_secret("secret")
+        .scope("management")
+        .build()
+        .unwrap();
+
+    EdcConnectorClient::builder()
+        .management_url(format!("{}/management", server.uri()))
+        .with_auth(Auth::oauth2_client_credentials(credentials))
+        .build()
+        .unwrap()
+}
+
+pub fn setup_retry_client(server: &MockServer, retry_policy: RetryPolicy) -> EdcConnectorClient {
+    EdcConnectorClient::builder()
+        .management_url(format!("{}/management", server.uri()))
+        .with_retry_policy(retry_policy)
+        .build()
+        .unwrap()
+}
+
+pub fn asset(id: &str) -> serde_json::Value {
+    json!({
+        "@context": { "@vocab": "https://w3id.org/edc/v0.0.1/ns/" },
+        "@id": id,
+        "properties": {},
+        "privateProperties": {},
+        "dataAddress": { "type": "HttpData" }
+    })
+}
+
+pub fn new_asset(id: &str, name: &str) -> NewAsset {
+    NewAsset::builder()
+        .id(id)
+        .property("name", name)
+        .property("tags", vec!["finance"])
+        .data_address(
+            DataAddress::builder()
+                .kind("HttpData")
+                .property("baseUrl", "https://example.com")
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap()
+}
+
+/// The asset of [`new_asset`] as stored by the connector.
+pub fn stored_asset(id: &str, name: &str) -> serde_json::Value {
+    json!({
+        "@context": { "@vocab": "https://w3id.org/edc/v0.0.1/ns/" },
+        "@id": id,
+        "@type": "Asset",
+        "properties": { "id": id, "name": name, "tags": "finance" },
+        "privateProperties": {},
+        "dataAddress": {
+            "@type": "DataAddress",
+            "type": "HttpData",
+            "baseUrl": "https://example.com"
+        }
+    })
+}
+
+pub fn dataset(id: &str, name: &str) -> serde_json::Value {
+    json!({
+        "@id": id,
+        "@type": "dcat:Dataset",
+        "odrl:hasPolicy": [],
+        "dcat:distribution": { "dct:format": { "@id": "HttpData-PULL" } },
+        "name": name
+    })
+}
+
+/// The dataset `asset-id` with the offer `offer-id`.
+pub fn offered_dataset() -> Dataset {
+    let mut dataset = dataset("asset-id", "Users");
+    dataset["odrl:hasPolicy"] = json!([{ "@id": "offer-id", "@type": "odrl:Offer" }]);
+    serde_json::from_value(dataset).unwrap()
+}
+
+pub fn catalog(
+    datasets: Vec<serde_json::Value>,
+    catalogs: Vec<serde_json::Value>,
+) -> serde_json::Value {
+    json!({
+        "@context": { "@vocab": "https://w3id.org/edc/v0.0.1/ns/" },
+        "@type": "dcat:Catalog",
+        "dcat:dataset": datasets,
+        "dcat:catalog": catalogs
+    })
+}
+
+pub fn id_response(id: &str) -> serde_json::Value {
+    json!({
+        "@context": { "@vocab": "https://w3id.org/edc/v0.0.1/ns/" },
+        "@id": id,
+        "createdAt": 0
+    })
+}
+
+pub fn negotiation(state: &str) -> serde_json::Value {
+    json!({
+        "@context": { "@vocab": "https://w3id.org/edc/v0.0.1/ns/" },
+        "@id": "negotiation-id",
+        "state": state,
+        "contractAgreementId": "agreement-id",
+        "counterPartyId": "provider",
+        "counterPartyAddress": "http://provider/protocol",
+        "protocol": "dataspace-protocol-http",
+        "createdAt": 0,
+        "callbackAddresses": [],
+        "type": "CONSUMER",
+        "errorDetail": "Policy rejected"
+    })
+}
+
+pub fn transfer_process(state: &str) -> serde_json::Value {
+    json!({
+        "@context": { "@vocab": "https://w3id.org/edc/v0.0.1/ns/" },
+        "@id": "transfer-id",
+        "state": state,
+        "stateTimestamp": 0,
+        "assetId": "asset-id",
+        "contractId": "agreement-id",
+        "callbackAddresses": [],
+        "transferType": "HttpData-PULL",
+        "type": "CONSUMER"
+    })
+}
+
+/// Mounts a create (`POST`), update (`PUT`) or delete (`DELETE`) expected the
+/// given number of times.
+pub async fn mount_write(server: &MockServer, http_method: &str, url: &str, times: u64) {
+    let response = match http_method {
+        "POST" => ResponseTemplate::new(200).set_body_json(id_response("1")),
+        _ => ResponseTemplate::new(204),
+    };
+
+    Mock::given(method(http_method))
+        .and(path(url))
+        .respond_with(response)
+        .expect(times)
+        .mount(server)
+        .await;
+}
+
+pub fn wait_options() -> WaitOptions {
+    WaitOptions::builder()
+        .interval(Duration::from_millis(10))
+        .max_interval(Duration::from_millis(50))
+        .timeout(Duration::from_secs(5))
+        .build()
+}
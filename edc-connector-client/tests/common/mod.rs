@@ -1,19 +1,21 @@
 #![allow(dead_code)]
 
+pub mod mock;
+
 use std::{future::Future, time::Duration};
 
 use edc_connector_client::{
     types::{
         asset::NewAsset,
-        catalog::DatasetRequest,
+        catalog::{Dataset, DatasetRequest},
         contract_definition::NewContractDefinition,
         contract_negotiation::{ContractNegotiationState, ContractRequest},
         data_address::DataAddress,
         policy::{NewPolicyDefinition, Policy, PolicyKind, Target},
         query::Criterion,
-        transfer_process::{TransferProcessState, TransferRequest},
+        transfer_process::TransferProcessState,
     },
-    Auth, EdcConnectorClient, EDC_NAMESPACE,
+    Auth, EdcConnectorClient, WaitOptions, EDC_NAMESPACE,
 };
 use tokio::time::sleep;
 use uuid::Uuid;
@@ -84,11 +86,11 @@ pub async fn seed(client: &EdcConnectorClient) -> (String, String, String) {
     )
 }
 
-pub async fn seed_contract_negotiation(
+pub async fn seed_dataset(
     consumer: &EdcConnectorClient,
     provider: &EdcConnectorClient,
-) -> (String, String) {
-    let (asset_id, _, _) = seed(&provider).await;
+) -> (Dataset, String) {
+    let (asset_id, _, _) = seed(provider).await;
 
     let dataset_request = DatasetRequest::builder()
         .counter_party_address(PROVIDER_PROTOCOL)
@@ -102,6 +104,15 @@ pub async fn seed_contract_negotiation(
         .await
         .unwrap();
 
+    (dataset, asset_id)
+}
+
+pub async fn seed_contract_negotiation(
+    consumer: &EdcConnectorClient,
+    provider: &EdcConnectorClient,
+) -> (String, String) {
+    let (dataset, asset_id) = seed_dataset(consumer, provider).await;
+
     let offer_id = dataset.offers()[0].id().unwrap();
 
     let request = ContractRequest::builder()
@@ -109,7 +120,7 @@ pub async fn seed_contract_negotiation(
         .counter_party_id(PROVIDER_ID)
         .policy(
             Policy::builder()
-                .id(&offer_id)
+                .id(offer_id)
                 .kind(PolicyKind::Offer)
                 .assigner(PROVIDER_ID)
                 .target(Target::id(&asset_id))
@@ -131,32 +142,20 @@ pub async fn seed_contract_agreement(
     consumer: &EdcConnectorClient,
     provider: &EdcConnectorClient,
 ) -> (String, String, String) {
-    let (contract_negotiation_id, asset_id) = seed_contract_negotiation(consumer, provider).await;
-
-    wait_for_negotiation_state(
-        &consumer,
-        &contract_negotiation_id,
-        ContractNegotiationState::Finalized,
-    )
-    .await;
+    let (dataset, asset_id) = seed_dataset(consumer, provider).await;
 
-    let agreement_id = consumer
-        .contract_negotiations()
-        .get(&contract_negotiation_id)
-        .await
-        .map(|cn| cn.contract_agreement_id().cloned())
-        .unwrap()
-        .unwrap();
-
-    let contract_agreement = consumer
-        .contract_agreements()
-        .get(&agreement_id)
+    let negotiated = consumer
+        .consume(&dataset, &dataset.offers()[0])
+        .counter_party_id(PROVIDER_ID)
+        .counter_party_address(PROVIDER_PROTOCOL)
+        .wait_options(wait_options())
+        .negotiate()
         .await
         .unwrap();
 
     (
-        contract_agreement.id().to_string(),
-        contract_negotiation_id,
+        negotiated.agreement_id().to_string(),
+        negotiated.negotiation().id().to_string(),
         asset_id,
     )
 }
@@ -165,75 +164,51 @@ pub async fn seed_transfer_process(
     consumer: &EdcConnectorClient,
     provider: &EdcConnectorClient,
 ) -> (String, String, String, String) {
-    let (contract_negotiation_id, asset_id) = seed_contract_negotiation(consumer, provider).await;
-
-    wait_for_negotiation_state(
-        &consumer,
-        &contract_negotiation_id,
-        ContractNegotiationState::Finalized,
-    )
-    .await;
+    let (dataset, asset_id) = seed_dataset(consumer, provider).await;
 
-    let agreement_id = consumer
-        .contract_negotiations()
-        .get(&contract_negotiation_id)
-        .await
-        .map(|cn| cn.contract_agreement_id().cloned())
-        .unwrap()
-        .unwrap();
-
-    let contract_agreement = consumer
-        .contract_agreements()
-        .get(&agreement_id)
-        .await
-        .unwrap();
-
-    let request = TransferRequest::builder()
+    let negotiated = consumer
+        .consume(&dataset, &dataset.offers()[0])
+        .counter_party_id(PROVIDER_ID)
         .counter_party_address(PROVIDER_PROTOCOL)
-        .contract_id(&agreement_id)
-        .transfer_type("HttpData-PULL")
-        .destination(DataAddress::builder().kind("HttpProxy").build().unwrap())
-        .build()
+        .wait_options(wait_options())
+        .negotiate()
+        .await
         .unwrap();
 
-    let response = consumer
-        .transfer_processes()
-        .initiate(&request)
+    let transfer_process = negotiated
+        .transfer(
+            "HttpData-PULL",
+            Some(DataAddress::builder().kind("HttpProxy").build().unwrap()),
+        )
         .await
         .unwrap();
 
     (
-        response.id().to_string(),
-        contract_agreement.id().to_string(),
-        contract_negotiation_id,
+        transfer_process.id().to_string(),
+        negotiated.agreement_id().to_string(),
+        negotiated.negotiation().id().to_string(),
         asset_id,
     )
 }
 
+pub fn wait_options() -> WaitOptions {
+    WaitOptions::builder()
+        .interval(Duration::from_millis(200))
+        .multiplier(1.0)
+        .timeout(Duration::from_secs(30))
+        .build()
+}
+
 pub async fn wait_for_negotiation_state(
     client: &EdcConnectorClient,
     id: &str,
     state: ContractNegotiationState,
 ) {
-    wait_for(|| {
-        let i_state = state.clone();
-        async {
-            client
-                .contract_negotiations()
-                .get_state(id)
-                .await
-                .map_err(|err| err.to_string())
-                .and_then(|s| {
-                    if s == state {
-                        Ok(i_state)
-                    } else {
-                        Err("State mismatch".to_string())
-                    }
-                })
-        }
-    })
-    .await
-    .unwrap();
+    client
+        .contract_negotiations()
+        .wait_for_state(id, state, &wait_options())
+        .await
+        .unwrap();
 }
 
 pub async fn wait_for_transfer_state(
@@ -241,25 +216,11 @@ pub async fn wait_for_transfer_state(
     id: &str,
     state: TransferProcessState,
 ) {
-    wait_for(|| {
-        let i_state = state.clone();
-        async {
-            client
-                .transfer_processes()
-                .get_state(id)
-                .await
-                .map_err(|err| err.to_string())
-                .and_then(|s| {
-                    if s == state {
-                        Ok(i_state)
-                    } else {
-                        Err("State mismatch".to_string())
-                    }
-                })
-        }
-    })
-    .await
-    .unwrap();
+    client
+        .transfer_processes()
+        .wait_for_state(id, state, &wait_options())
+        .await
+        .unwrap();
 }
 
 pub async fn wait_for<F, Fut, R, E>(f: F) -> Result<R, E>
@@ -0,0 +1,179 @@
+mod common;
+
+use std::time::Duration;
+
+use edc_connector_client::{types::data_address::DataAddress, Error, WaitOptions, WorkflowError};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_partial_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use common::mock::{id_response, negotiation, offered_dataset, setup_client, transfer_process};
+
+async fn mount_negotiation(server: &MockServer, state: &str) {
+    Mock::given(method("POST"))
+        .and(path("/management/v3/contractnegotiations"))
+        .and(body_partial_json(json!({
+            "counterPartyId": "provider",
+            "policy": {
+                "@id": "offer-id",
+                "@type": "Offer",
+                "assigner": "provider",
+                "target": { "@id": "asset-id" }
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(id_response("negotiation-id")))
+        .expect(1)
+        .mount(server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/management/v3/contractnegotiations/negotiation-id"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(negotiation(state)))
+        .mount(server)
+        .await;
+}
+
+async fn mount_transfer(server: &MockServer, state: &str) {
+    Mock::given(method("POST"))
+        .and(path("/management/v3/transferprocesses"))
+        .and(body_partial_json(json!({
+            "contractId": "agreement-id",
+            "transferType": "HttpData-PULL"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(id_response("transfer-id")))
+        .expect(1)
+        .mount(server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/management/v3/transferprocesses/transfer-id"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(transfer_process(state)))
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn should_negotiate_and_start_a_transfer() {
+    let server = MockServer::start().await;
+    mount_negotiation(&server, "FINALIZED").await;
+    mount_transfer(&server, "STARTED").await;
+
+    let client = setup_client(&server);
+    let dataset = offered_dataset();
+
+    let transfer_process = client
+        .consume(&dataset, &dataset.offers()[0])
+        .counter_party_id("provider")
+        .counter_party_address("http://provider/protocol")
+        .transfer(
+            "HttpData-PULL",
+            Some(DataAddress::builder().kind("HttpProxy").build().unwrap()),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(transfer_process.id(), "transfer-id");
+    assert_eq!(transfer_process.contract_id(), "agreement-id");
+}
+
+#[tokio::test]
+async fn should_report_a_terminated_negotiation() {
+    let server = MockServer::start().await;
+    mount_negotiation(&server, "TERMINATED").await;
+
+    let client = setup_client(&server);
+    let dataset = offered_dataset();
+
+    let result = client
+        .consume(&dataset, &dataset.offers()[0])
+        .counter_party_id("provider")
+        .counter_party_address("http://provider/protocol")
+        .negotiate()
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(Error::Workflow(WorkflowError::NegotiationTerminated { id, error_detail }))
+            if id == "negotiation-id" && error_detail.as_deref() == Some("Policy rejected")
+    ));
+}
+
+#[tokio::test]
+async fn should_time_out_waiting_for_the_transfer() {
+    let server = MockServer::start().await;
+    mount_negotiation(&server, "FINALIZED").await;
+    mount_transfer(&server, "REQUESTED").await;
+
+    let client = setup_client(&server);
+    let dataset = offered_dataset();
+
+    let result = client
+        .consume(&dataset, &dataset.offers()[0])
+        .counter_party_id("provider")
+        .counter_party_address("http://provider/protocol")
+        .wait_options(
+            WaitOptions::builder()
+                .interval(Duration::from_millis(50))
+                .timeout(Duration::from_millis(300))
+                .build(),
+        )
+        .transfer("HttpData-PULL", None)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(Error::Workflow(WorkflowError::Timeout(_)))
+    ));
+}
+
+#[tokio::test]
+async fn should_terminate_the_negotiation_when_cancelled() {
+    let server = MockServer::start().await;
+    mount_negotiation(&server, "REQUESTED").await;
+
+    Mock::given(method("POST"))
+        .and(path(
+            "/management/v3/contractnegotiations/negotiation-id/terminate",
+        ))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = setup_client(&server);
+    let dataset = offered_dataset();
+
+    let result = client
+        .consume(&dataset, &dataset.offers()[0])
+        .counter_party_id("provider")
+        .counter_party_address("http://provider/protocol")
+        .wait_options(
+            WaitOptions::builder()
+                .interval(Duration::from_millis(50))
+                .build(),
+        )
+        .cancel_on(tokio::time::sleep(Duration::from_millis(200)))
+        .negotiate()
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(Error::Workflow(WorkflowError::Cancelled))
+    ));
+}
+
+#[tokio::test]
+async fn should_require_the_counter_party() {
+    let server = MockServer::start().await;
+    let client = setup_client(&server);
+    let dataset = offered_dataset();
+
+    let result = client
+        .consume(&dataset, &dataset.offers()[0])
+        .negotiate()
+        .await;
+
+    assert!(matches!(result, Err(Error::Builder(_))));
+}
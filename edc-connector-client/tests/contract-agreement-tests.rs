@@ -41,6 +41,31 @@ mod get {
     }
 }
 
+mod get_negotiation {
+    use crate::common::{seed_contract_agreement, setup_consumer_client, setup_provider_client};
+
+    #[tokio::test]
+    async fn should_get_the_negotiation_of_a_contract_agreement() {
+        let provider = setup_provider_client();
+        let consumer = setup_consumer_client();
+
+        let (agreement_id, contract_negotiation_id, _) =
+            seed_contract_agreement(&consumer, &provider).await;
+
+        let contract_negotiation = consumer
+            .contract_agreements()
+            .get_negotiation(&agreement_id)
+            .await
+            .unwrap();
+
+        assert_eq!(contract_negotiation_id, contract_negotiation.id());
+        assert_eq!(
+            Some(&agreement_id),
+            contract_negotiation.contract_agreement_id()
+        );
+    }
+}
+
 mod query {
     use edc_connector_client::types::{
         contract_negotiation::ContractNegotiationState, query::Query,
@@ -42,7 +42,7 @@ mod initiate {
             .policy(
                 Policy::builder()
                     .kind(PolicyKind::Offer)
-                    .id(&offer_id)
+                    .id(offer_id)
                     .assigner(PROVIDER_ID)
                     .target(Target::id(&asset_id))
                     .build(),
@@ -85,7 +85,7 @@ mod initiate {
             .counter_party_id(PROVIDER_ID)
             .policy(
                 Policy::builder()
-                    .id(&offer_id)
+                    .id(offer_id)
                     .assigner(PROVIDER_ID)
                     .target(Target::id(&asset_id))
                     .build(),
@@ -149,6 +149,28 @@ mod get {
     }
 }
 
+mod get_agreement {
+    use crate::common::{seed_contract_agreement, setup_consumer_client, setup_provider_client};
+
+    #[tokio::test]
+    async fn should_get_the_agreement_of_a_contract_negotiation() {
+        let provider = setup_provider_client();
+        let consumer = setup_consumer_client();
+
+        let (agreement_id, contract_negotiation_id, asset_id) =
+            seed_contract_agreement(&consumer, &provider).await;
+
+        let contract_agreement = consumer
+            .contract_negotiations()
+            .get_agreement(&contract_negotiation_id)
+            .await
+            .unwrap();
+
+        assert_eq!(agreement_id, contract_agreement.id());
+        assert_eq!(asset_id, contract_agreement.asset_id());
+    }
+}
+
 mod query {
     use edc_connector_client::types::query::Query;
 
@@ -0,0 +1,393 @@
+#![cfg(feature = "crawler")]
+
+mod common;
+
+use std::time::{Duration, Instant};
+
+use edc_connector_client::{
+    crawler::{CatalogIndex, Crawler, Participant, Search},
+    Error, WorkflowError,
+};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_partial_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use common::mock::{catalog, dataset, setup_client};
+
+fn address(participant_id: &str) -> String {
+    format!("http://{}/protocol", participant_id)
+}
+
+async fn mount_catalog(
+    server: &MockServer,
+    participant_id: &str,
+    offset: u32,
+    page: serde_json::Value,
+) {
+    Mock::given(method("POST"))
+        .and(path("/management/v3/catalog/request"))
+        .and(body_partial_json(json!({
+            "counterPartyAddress": address(participant_id),
+            "counterPartyId": participant_id,
+            "querySpec": { "offset": offset }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page))
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+fn crawler(server: &MockServer, participants: &[&str]) -> Crawler {
+    Crawler::builder(setup_client(server))
+        .participants(
+            participants
+                .iter()
+                .map(|id| Participant::new(*id, address(id))),
+        )
+        .page_size(2)
+        .build()
+}
+
+fn ids(index: &CatalogIndex, search: &Search) -> Vec<String> {
+    index
+        .search(search)
+        .iter()
+        .map(|found| format!("{}/{}", found.participant_id(), found.dataset().id()))
+        .collect()
+}
+
+#[tokio::test]
+async fn should_crawl_the_participants_into_the_index() {
+    let server = MockServer::start().await;
+
+    mount_catalog(
+        &server,
+        "provider-a",
+        0,
+        catalog(
+            vec![dataset("users", "Users"), dataset("orders", "Orders")],
+            vec![],
+        ),
+    )
+    .await;
+    mount_catalog(
+        &server,
+        "provider-a",
+        2,
+        catalog(vec![dataset("invoices", "Invoices")], vec![]),
+    )
+    .await;
+    mount_catalog(
+        &server,
+        "provider-b",
+        0,
+        catalog(vec![dataset("users", "Users")], vec![]),
+    )
+    .await;
+
+    let mut index = CatalogIndex::new();
+    let report = crawler(&server, &["provider-a", "provider-b"])
+        .crawl(&mut index)
+        .await;
+
+    assert!(report.is_success());
+    assert_eq!(report.participants().len(), 2);
+    assert_eq!(index.len(), 4);
+    assert_eq!(
+        ids(&index, &Search::new().property("name", "Users")),
+        vec!["provider-a/users", "provider-b/users"]
+    );
+    assert_eq!(
+        ids(
+            &index,
+            &Search::new()
+                .participant("provider-a")
+                .property_contains("name", "voice")
+                .transfer_type("HttpData-PULL")
+        ),
+        vec!["provider-a/invoices"]
+    );
+}
+
+#[tokio::test]
+async fn should_refresh_only_the_stale_participants() {
+    let server = MockServer::start().await;
+
+    mount_catalog(
+        &server,
+        "provider-a",
+        0,
+        catalog(vec![dataset("users", "Users")], vec![]),
+    )
+    .await;
+
+    let mut index = CatalogIndex::new();
+
+    crawler(&server, &["provider-a"]).refresh(&mut index).await;
+
+    let report = crawler(&server, &["provider-a"]).refresh(&mut index).await;
+
+    assert!(report.participants().is_empty());
+    assert_eq!(index.len(), 1);
+    assert!(index
+        .participant("provider-a")
+        .unwrap()
+        .crawled_at()
+        .is_some());
+}
+
+#[tokio::test]
+async fn should_report_the_changes_since_the_previous_crawl() {
+    let server = MockServer::start().await;
+    let crawler = crawler(&server, &["provider-a"]);
+    let mut index = CatalogIndex::new();
+
+    mount_catalog(
+        &server,
+        "provider-a",
+        0,
+        catalog(
+            vec![dataset("users", "Users"), dataset("orders", "Orders")],
+            vec![],
+        ),
+    )
+    .await;
+    mount_catalog(&server, "provider-a", 2, catalog(vec![], vec![])).await;
+
+    let report = crawler.crawl(&mut index).await;
+    let changes = report.participants()[0].changes().unwrap();
+
+    assert_eq!(changes.added(), ["orders", "users"]);
+
+    server.reset().await;
+    mount_catalog(
+        &server,
+        "provider-a",
+        0,
+        catalog(
+            vec![
+                dataset("users", "All users"),
+                dataset("invoices", "Invoices"),
+            ],
+            vec![],
+        ),
+    )
+    .await;
+    mount_catalog(&server, "provider-a", 2, catalog(vec![], vec![])).await;
+
+    let report = crawler.crawl(&mut index).await;
+    let changes = report.participants()[0].changes().unwrap();
+
+    assert_eq!(changes.added(), ["invoices"]);
+    assert_eq!(changes.updated(), ["users"]);
+    assert_eq!(changes.removed(), ["orders"]);
+    assert_eq!(
+        index
+            .dataset("provider-a", "users")
+            .unwrap()
+            .property::<String>("name")
+            .unwrap(),
+        Some("All users".to_string())
+    );
+}
+
+#[tokio::test]
+async fn should_keep_the_datasets_of_a_failed_participant() {
+    let server = MockServer::start().await;
+    let crawler = crawler(&server, &["provider-a"]);
+    let mut index = CatalogIndex::new();
+
+    mount_catalog(
+        &server,
+        "provider-a",
+        0,
+        catalog(vec![dataset("users", "Users")], vec![]),
+    )
+    .await;
+
+    crawler.crawl(&mut index).await;
+
+    server.reset().await;
+    Mock::given(method("POST"))
+        .and(path("/management/v3/catalog/request"))
+        .respond_with(ResponseTemplate::new(502))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let report = crawler.crawl(&mut index).await;
+
+    assert!(!report.is_success());
+    assert_eq!(report.failures().count(), 1);
+    assert!(report.participants()[0].error().is_some());
+    assert_eq!(index.len(), 1);
+    assert!(index
+        .participant("provider-a")
+        .unwrap()
+        .last_error()
+        .is_some());
+
+    server.reset().await;
+    mount_catalog(
+        &server,
+        "provider-a",
+        0,
+        catalog(vec![dataset("users", "Users")], vec![]),
+    )
+    .await;
+
+    // Failed participants are retried even if crawled recently
+    let report = crawler.refresh(&mut index).await;
+
+    assert!(report.is_success());
+    assert!(report.participants()[0].changes().unwrap().is_empty());
+    assert!(index
+        .participant("provider-a")
+        .unwrap()
+        .last_error()
+        .is_none());
+}
+
+#[tokio::test]
+async fn should_rate_limit_the_requests() {
+    let server = MockServer::start().await;
+    let participants = ["provider-a", "provider-b", "provider-c", "provider-d"];
+
+    for participant in participants {
+        mount_catalog(&server, participant, 0, catalog(vec![], vec![])).await;
+    }
+
+    let crawler = Crawler::builder(setup_client(&server))
+        .participants(
+            participants
+                .iter()
+                .map(|id| Participant::new(*id, address(id))),
+        )
+        .concurrency(4)
+        .rate_limit(2, Duration::from_millis(200))
+        .build();
+
+    let started = Instant::now();
+    let report = crawler.crawl(&mut CatalogIndex::new()).await;
+
+    assert!(report.is_success());
+    assert!(started.elapsed() >= Duration::from_millis(300));
+}
+
+#[tokio::test]
+async fn should_time_out_a_hanging_participant() {
+    let server = MockServer::start().await;
+
+    mount_catalog(
+        &server,
+        "provider-a",
+        0,
+        catalog(vec![dataset("users", "Users")], vec![]),
+    )
+    .await;
+    Mock::given(method("POST"))
+        .and(path("/management/v3/catalog/request"))
+        .and(body_partial_json(
+            json!({ "counterPartyAddress": address("provider-b") }),
+        ))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(catalog(vec![], vec![]))
+                .set_delay(Duration::from_secs(10)),
+        )
+        .mount(&server)
+        .await;
+
+    let crawler = Crawler::builder(setup_client(&server))
+        .participant(Participant::new("provider-a", address("provider-a")))
+        .participant(Participant::new("provider-b", address("provider-b")))
+        .timeout(Duration::from_millis(300))
+        .build();
+
+    let started = Instant::now();
+    let mut index = CatalogIndex::new();
+    let report = crawler.crawl(&mut index).await;
+
+    assert!(started.elapsed() < Duration::from_secs(5));
+    assert_eq!(
+        report
+            .failures()
+            .map(|failure| failure.participant_id())
+            .collect::<Vec<_>>(),
+        ["provider-b"]
+    );
+    assert!(matches!(
+        report.participants()[1].error(),
+        Some(Error::Workflow(WorkflowError::Timeout(_)))
+    ));
+    assert_eq!(index.len(), 1);
+}
+
+#[tokio::test]
+async fn should_not_count_the_rate_limit_in_the_timeout() {
+    let server = MockServer::start().await;
+    let participants = ["provider-a", "provider-b", "provider-c", "provider-d"];
+
+    for participant in participants {
+        mount_catalog(&server, participant, 0, catalog(vec![], vec![])).await;
+    }
+
+    let crawler = Crawler::builder(setup_client(&server))
+        .participants(
+            participants
+                .iter()
+                .map(|id| Participant::new(*id, address(id))),
+        )
+        .concurrency(4)
+        .rate_limit(1, Duration::from_millis(150))
+        .timeout(Duration::from_millis(300))
+        .build();
+
+    let report = crawler.crawl(&mut CatalogIndex::new()).await;
+
+    assert!(report.is_success());
+}
+
+#[tokio::test]
+async fn should_keep_crawling_next_to_a_slow_participant() {
+    let server = MockServer::start().await;
+
+    for (participant, delay) in [("provider-a", 800), ("provider-b", 0), ("provider-c", 600)] {
+        Mock::given(method("POST"))
+            .and(path("/management/v3/catalog/request"))
+            .and(body_partial_json(
+                json!({ "counterPartyAddress": address(participant) }),
+            ))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(catalog(vec![], vec![]))
+                    .set_delay(Duration::from_millis(delay)),
+            )
+            .mount(&server)
+            .await;
+    }
+
+    let crawler = Crawler::builder(setup_client(&server))
+        .participants(
+            ["provider-a", "provider-b", "provider-c"]
+                .iter()
+                .map(|id| Participant::new(*id, address(id))),
+        )
+        .concurrency(2)
+        .build();
+
+    let started = Instant::now();
+    let report = crawler.crawl(&mut CatalogIndex::new()).await;
+
+    assert!(started.elapsed() < Duration::from_millis(1300));
+    assert_eq!(
+        report
+            .participants()
+            .iter()
+            .map(|report| report.participant_id())
+            .collect::<Vec<_>>(),
+        ["provider-a", "provider-b", "provider-c"]
+    );
+}
@@ -7,5 +7,5 @@ async fn should_fetch_dataplanes() {
     let client = setup_provider_client();
 
     let response = client.data_planes().list().await.unwrap();
-    assert!(response.len() > 0);
+    assert!(!response.is_empty());
 }
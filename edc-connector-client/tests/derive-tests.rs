@@ -0,0 +1,153 @@
+#![cfg(feature = "derive")]
+
+use edc_connector_client::{
+    types::{
+        asset::{Asset, NewAsset},
+        data_address::DataAddress,
+        properties::EdcProperties,
+    },
+    EDC_NAMESPACE,
+};
+use serde_json::json;
+
+const DCT: &str = "http://purl.org/dc/terms/";
+
+#[derive(Debug, PartialEq, EdcProperties)]
+#[edc(namespace = EDC_NAMESPACE)]
+struct Metadata {
+    name: String,
+    #[edc(rename = "contenttype")]
+    content_type: Option<String>,
+    #[edc(namespace = DCT)]
+    issued: u64,
+    #[edc(default)]
+    tags: Vec<String>,
+    #[edc(skip)]
+    cached: bool,
+}
+
+#[derive(Debug, PartialEq, EdcProperties)]
+struct Secrets {
+    #[edc(namespace = "https://example.com/ns/")]
+    api_key: String,
+}
+
+fn data_address() -> DataAddress {
+    DataAddress::builder().kind("HttpData").build().unwrap()
+}
+
+fn asset(properties: serde_json::Value) -> Asset {
+    serde_json::from_value(json!({
+        "@id": "asset-id",
+        "properties": properties,
+        "privateProperties": {
+            "https://example.com/ns/api_key": "secret"
+        },
+        "dataAddress": { "type": "HttpData" }
+    }))
+    .unwrap()
+}
+
+#[test]
+fn should_read_typed_properties() {
+    let asset = asset(json!({
+        "name": "report",
+        "contenttype": "application/json",
+        "http://purl.org/dc/terms/issued": 1714566600,
+        "tags": ["finance", "quarterly"]
+    }));
+
+    let metadata = asset.properties_as::<Metadata>().unwrap();
+
+    assert_eq!(
+        metadata,
+        Metadata {
+            name: "report".to_string(),
+            content_type: Some("application/json".to_string()),
+            issued: 1714566600,
+            tags: vec!["finance".to_string(), "quarterly".to_string()],
+            cached: false,
+        }
+    );
+
+    let secrets = asset.private_properties_as::<Secrets>().unwrap();
+
+    assert_eq!(secrets.api_key, "secret");
+}
+
+#[test]
+fn should_read_expanded_edc_properties() {
+    let asset = asset(json!({
+        "https://w3id.org/edc/v0.0.1/ns/name": "report",
+        "http://purl.org/dc/terms/issued": "1714566600"
+    }));
+
+    let metadata = asset.properties_as::<Metadata>().unwrap();
+
+    assert_eq!(metadata.name, "report");
+    assert_eq!(metadata.content_type, None);
+    assert_eq!(metadata.tags, Vec::<String>::new());
+}
+
+#[test]
+fn should_write_typed_properties() {
+    let asset = NewAsset::builder()
+        .properties_from(Metadata {
+            name: "report".to_string(),
+            content_type: None,
+            issued: 1714566600,
+            tags: vec!["finance".to_string()],
+            cached: true,
+        })
+        .private_properties_from(Secrets {
+            api_key: "secret".to_string(),
+        })
+        .data_address(data_address())
+        .build()
+        .unwrap();
+
+    let json = serde_json::to_value(&asset).unwrap();
+
+    assert_eq!(
+        json["properties"],
+        json!({
+            "name": "report",
+            "http://purl.org/dc/terms/issued": 1714566600,
+            "tags": ["finance"]
+        })
+    );
+    assert_eq!(
+        json["privateProperties"],
+        json!({ "https://example.com/ns/api_key": "secret" })
+    );
+}
+
+#[test]
+fn should_name_the_field_failing_to_convert() {
+    let asset = asset(json!({
+        "name": "report",
+        "http://purl.org/dc/terms/issued": "yesterday"
+    }));
+
+    let error = asset.properties_as::<Metadata>().unwrap_err();
+
+    assert_eq!(error.field(), Some("issued"));
+    assert!(!error.is_missing());
+    assert_eq!(
+        error.to_string(),
+        "Failed to convert property `http://purl.org/dc/terms/issued` of field `issued`: expected u64, found string"
+    );
+}
+
+#[test]
+fn should_name_the_missing_field() {
+    let asset = asset(json!({
+        "http://purl.org/dc/terms/issued": 1714566600
+    }));
+
+    let error = asset.properties_as::<Metadata>().unwrap_err();
+
+    assert_eq!(error.field(), Some("name"));
+    assert_eq!(error.path(), "name");
+    assert!(error.is_missing());
+}
@@ -0,0 +1,204 @@
+mod common;
+
+use edc_connector_client::{types::data_address::DataAddress, Error};
+use serde_json::json;
+use wiremock::{
+    matchers::{header, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use common::mock::setup_client;
+
+fn data_address(server: &MockServer, token: &str, expires_in: &str) -> serde_json::Value {
+    json!({
+        "@context": { "@vocab": "https://w3id.org/edc/v0.0.1/ns/" },
+        "@type": "DataAddress",
+        "type": "https://w3id.org/idsa/v4.1/HTTP",
+        "endpoint": format!("{}/public", server.uri()),
+        "authType": "bearer",
+        "authorization": token,
+        "expiresIn": expires_in
+    })
+}
+
+#[tokio::test]
+async fn should_request_an_auto_refreshed_data_address() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/management/v3/edrs/transfer-id/dataaddress"))
+        .and(query_param("auto_refresh", "true"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(data_address(&server, "token", "300")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let data_address = setup_client(&server)
+        .edrs()
+        .get_data_address_with_auto_refresh("transfer-id", true)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        data_address.property::<String>("authorization").unwrap(),
+        Some("token".to_string())
+    );
+}
+
+#[tokio::test]
+async fn should_refresh_an_edr() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/management/v3/edrs/transfer-id/refresh"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(data_address(
+            &server,
+            "refreshed",
+            "300",
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let data_address = setup_client(&server)
+        .edrs()
+        .refresh("transfer-id")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        data_address.property::<String>("authorization").unwrap(),
+        Some("refreshed".to_string())
+    );
+}
+
+#[tokio::test]
+async fn should_reuse_a_fresh_edr() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/management/v3/edrs/transfer-id/dataaddress"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(data_address(&server, "token", "3600")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/public/users"))
+        .and(header("Authorization", "token"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let client = setup_client(&server);
+    let edrs = client.edrs();
+    let handle = edrs.handle("transfer-id");
+
+    for _ in 0..2 {
+        handle
+            .public_api()
+            .await
+            .unwrap()
+            .get("users")
+            .send()
+            .await
+            .unwrap();
+    }
+}
+
+#[tokio::test]
+async fn should_refresh_the_edr_before_it_expires() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/management/v3/edrs/transfer-id/dataaddress"))
+        .and(query_param("auto_refresh", "true"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(data_address(&server, "token", "10")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/management/v3/edrs/transfer-id/refresh"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(data_address(
+            &server,
+            "refreshed",
+            "3600",
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = setup_client(&server);
+    let edrs = client.edrs();
+    let handle = edrs.handle("transfer-id");
+
+    let token =
+        |data_address: DataAddress| data_address.property::<String>("authorization").unwrap();
+
+    // Expires within the refresh skew, refreshed on the next call
+    assert_eq!(
+        token(handle.data_address().await.unwrap()),
+        Some("token".to_string())
+    );
+    assert_eq!(
+        token(handle.data_address().await.unwrap()),
+        Some("refreshed".to_string())
+    );
+    // The refreshed token is cached for its full lifetime
+    assert_eq!(
+        token(handle.data_address().await.unwrap()),
+        Some("refreshed".to_string())
+    );
+}
+
+#[tokio::test]
+async fn should_fetch_the_edr_again_when_invalidated() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/management/v3/edrs/transfer-id/dataaddress"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(data_address(&server, "token", "3600")),
+        )
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let client = setup_client(&server);
+    let edrs = client.edrs();
+    let handle = edrs.handle("transfer-id");
+
+    handle.data_address().await.unwrap();
+    handle.invalidate().await;
+    handle.data_address().await.unwrap();
+}
+
+#[tokio::test]
+async fn should_reject_an_edr_with_an_invalid_expiry() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/management/v3/edrs/transfer-id/dataaddress"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(data_address(&server, "token", "soon")),
+        )
+        .mount(&server)
+        .await;
+
+    let client = setup_client(&server);
+    let edrs = client.edrs();
+    let handle = edrs.handle("transfer-id");
+
+    assert!(matches!(
+        handle.data_address().await,
+        Err(Error::Conversion(_))
+    ));
+}
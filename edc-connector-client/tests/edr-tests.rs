@@ -107,3 +107,57 @@ mod delete {
         ))
     }
 }
+
+mod public_api {
+
+    use edc_connector_client::types::{
+        data_address::DataAddress, transfer_process::TransferProcessState,
+    };
+
+    use crate::common::{seed_transfer_process, wait_for};
+    use crate::common::{setup_consumer_client, setup_provider_client, wait_for_transfer_state};
+
+    #[tokio::test]
+    async fn should_pull_data_with_an_edr() {
+        let provider = setup_provider_client();
+        let consumer = setup_consumer_client();
+
+        let (transfer_process_id, _, _, _) = seed_transfer_process(&consumer, &provider).await;
+
+        wait_for_transfer_state(
+            &consumer,
+            &transfer_process_id,
+            TransferProcessState::Started,
+        )
+        .await;
+
+        let edr =
+            wait_for(|| async { consumer.edrs().get_data_address(&transfer_process_id).await })
+                .await
+                .unwrap();
+
+        // The endpoint advertised by the provider is only resolvable inside the docker network
+        let edr = DataAddress::builder()
+            .kind("https://w3id.org/idsa/v4.1/HTTP")
+            .property("endpoint", "http://localhost:29291/public")
+            .property(
+                "authorization",
+                edr.property::<String>("authorization").unwrap().unwrap(),
+            )
+            .build()
+            .unwrap();
+
+        let users = consumer
+            .public_api(&edr)
+            .unwrap()
+            .get("/")
+            .send()
+            .await
+            .unwrap()
+            .json::<serde_json::Value>()
+            .await
+            .unwrap();
+
+        assert!(users.as_array().is_some_and(|users| !users.is_empty()));
+    }
+}
@@ -0,0 +1,131 @@
+mod common;
+
+use edc_connector_client::Error;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use common::mock::{new_asset, setup_client};
+
+async fn mount_create(server: &MockServer, response: ResponseTemplate) {
+    Mock::given(method("POST"))
+        .and(path("/management/v3/assets"))
+        .respond_with(response)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn should_tell_not_found() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/management/v3/assets/1"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!([{
+            "message": "Object of type Asset with ID=1 was not found",
+            "type": "ObjectNotFound"
+        }])))
+        .mount(&server)
+        .await;
+
+    let err = setup_client(&server).assets().get("1").await.unwrap_err();
+
+    assert!(err.is_not_found());
+    assert!(!err.is_conflict());
+    assert!(err.validation_errors().is_empty());
+}
+
+#[tokio::test]
+async fn should_tell_conflict() {
+    let server = MockServer::start().await;
+
+    mount_create(
+        &server,
+        ResponseTemplate::new(409).set_body_json(json!([{
+            "message": "Object of type Asset with ID=1 already exists",
+            "type": "ObjectConflict"
+        }])),
+    )
+    .await;
+
+    let err = setup_client(&server)
+        .assets()
+        .create(&new_asset("1", "report"))
+        .await
+        .unwrap_err();
+
+    assert!(err.is_conflict());
+    assert!(!err.is_not_found());
+}
+
+#[tokio::test]
+async fn should_expose_validation_errors() {
+    let server = MockServer::start().await;
+
+    mount_create(
+        &server,
+        ResponseTemplate::new(400).set_body_json(json!([{
+            "message": "mandatory value 'https://w3id.org/edc/v0.0.1/ns/dataAddress' is missing or it is blank",
+            "type": "ValidationFailure",
+            "path": "https://w3id.org/edc/v0.0.1/ns/dataAddress",
+            "invalidValue": null
+        }])),
+    )
+    .await;
+
+    let err = setup_client(&server)
+        .assets()
+        .create(&new_asset("1", "report"))
+        .await
+        .unwrap_err();
+
+    let errors = err.validation_errors();
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].kind, "ValidationFailure");
+    assert_eq!(
+        errors[0].path.as_deref(),
+        Some("https://w3id.org/edc/v0.0.1/ns/dataAddress")
+    );
+}
+
+#[tokio::test]
+async fn should_tell_unauthorized() {
+    let server = MockServer::start().await;
+
+    mount_create(
+        &server,
+        ResponseTemplate::new(401).set_body_string("Request could not be authenticated"),
+    )
+    .await;
+
+    let err = setup_client(&server)
+        .assets()
+        .create(&new_asset("1", "report"))
+        .await
+        .unwrap_err();
+
+    assert!(err.is_unauthorized());
+    assert!(err.validation_errors().is_empty());
+}
+
+#[tokio::test]
+async fn should_split_decode_errors_from_transport_errors() {
+    let server = MockServer::start().await;
+
+    mount_create(
+        &server,
+        ResponseTemplate::new(200).set_body_string("<html>Gateway</html>"),
+    )
+    .await;
+
+    let err = setup_client(&server)
+        .assets()
+        .create(&new_asset("1", "report"))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, Error::Decode(ref decode) if decode.body == "<html>Gateway</html>"));
+}
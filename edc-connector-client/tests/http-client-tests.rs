@@ -0,0 +1,87 @@
+mod common;
+
+use std::time::Duration;
+
+use edc_connector_client::{EdcConnectorClient, Error};
+use reqwest::{header::HeaderMap, Client, Proxy};
+use wiremock::{
+    matchers::{header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use common::mock::asset;
+
+#[tokio::test]
+async fn should_time_out_slow_responses() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/management/v3/assets/1"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(asset("1"))
+                .set_delay(Duration::from_secs(2)),
+        )
+        .mount(&server)
+        .await;
+
+    let client = EdcConnectorClient::builder()
+        .management_url(format!("{}/management", server.uri()))
+        .timeout(Duration::from_millis(100))
+        .build()
+        .unwrap();
+
+    let response = client.assets().get("1").await;
+
+    assert!(matches!(response, Err(Error::Reqwest(err)) if err.is_timeout()));
+}
+
+#[tokio::test]
+async fn should_send_requests_through_the_proxy() {
+    let proxy = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/management/v3/assets/1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(asset("1")))
+        .expect(1)
+        .mount(&proxy)
+        .await;
+
+    let client = EdcConnectorClient::builder()
+        .management_url("http://edc.internal/management")
+        .proxy(Proxy::http(proxy.uri()).unwrap())
+        .build()
+        .unwrap();
+
+    let asset = client.assets().get("1").await.unwrap();
+
+    assert_eq!(asset.id(), "1");
+}
+
+#[tokio::test]
+async fn should_use_the_provided_http_client() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/management/v3/assets/1"))
+        .and(header("X-Tenant", "tenant"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(asset("1")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut headers = HeaderMap::new();
+    headers.insert("X-Tenant", "tenant".parse().unwrap());
+
+    let http_client = Client::builder().default_headers(headers).build().unwrap();
+
+    let client = EdcConnectorClient::builder()
+        .management_url(format!("{}/management", server.uri()))
+        .with_http_client(http_client)
+        .build()
+        .unwrap();
+
+    let asset = client.assets().get("1").await.unwrap();
+
+    assert_eq!(asset.id(), "1");
+}
@@ -0,0 +1,132 @@
+use std::sync::{Arc, Mutex};
+
+use edc_connector_client::{Auth, EdcConnectorClient, EdcResult, Error, Interceptor};
+use reqwest::{Request, Response};
+use serde_json::json;
+use wiremock::{
+    matchers::{header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+struct Header(&'static str, &'static str);
+
+#[async_trait::async_trait]
+impl Interceptor for Header {
+    async fn on_request(&self, request: &mut Request) -> EdcResult<()> {
+        request
+            .headers_mut()
+            .insert(self.0, self.1.parse().unwrap());
+        Ok(())
+    }
+}
+
+#[derive(Clone, Default)]
+struct Audit(Arc<Mutex<Vec<String>>>);
+
+#[async_trait::async_trait]
+impl Interceptor for Audit {
+    async fn on_request(&self, request: &mut Request) -> EdcResult<()> {
+        self.0
+            .lock()
+            .unwrap()
+            .push(format!("{} {}", request.method(), request.url().path()));
+        Ok(())
+    }
+
+    async fn on_response(&self, response: &mut Response) -> EdcResult<()> {
+        self.0
+            .lock()
+            .unwrap()
+            .push(response.status().as_u16().to_string());
+        Ok(())
+    }
+}
+
+struct Reject;
+
+#[async_trait::async_trait]
+impl Interceptor for Reject {
+    async fn on_request(&self, _request: &mut Request) -> EdcResult<()> {
+        Err(Error::Interceptor("rejected".into()))
+    }
+}
+
+async fn setup_server() -> MockServer {
+    let server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/management/v3/assets/1"))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&server)
+        .await;
+
+    server
+}
+
+#[tokio::test]
+async fn should_apply_interceptors_to_requests() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/management/v3/assets/request"))
+        .and(header("X-Api-Key", "123456"))
+        .and(header("X-Correlation-Id", "abc"))
+        .and(header("X-Tenant", "tenant"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = EdcConnectorClient::builder()
+        .management_url(format!("{}/management", server.uri()))
+        .with_auth(Auth::api_token("123456"))
+        .with_interceptor(Header("X-Correlation-Id", "abc"))
+        .with_interceptor(Header("X-Tenant", "tenant"))
+        .build()
+        .unwrap();
+
+    let assets = client.assets().query(Default::default()).await.unwrap();
+
+    assert!(assets.is_empty());
+}
+
+#[tokio::test]
+async fn should_observe_responses() {
+    let server = setup_server().await;
+    let audit = Audit::default();
+
+    let client = EdcConnectorClient::builder()
+        .management_url(format!("{}/management", server.uri()))
+        .with_interceptor(audit.clone())
+        .build()
+        .unwrap();
+
+    client.assets().delete("1").await.unwrap();
+
+    assert_eq!(
+        *audit.0.lock().unwrap(),
+        vec![
+            "DELETE /management/v3/assets/1".to_string(),
+            "204".to_string()
+        ]
+    );
+}
+
+#[tokio::test]
+async fn should_fail_when_an_interceptor_rejects_the_request() {
+    let server = setup_server().await;
+    let audit = Audit::default();
+
+    let client = EdcConnectorClient::builder()
+        .management_url(format!("{}/management", server.uri()))
+        .with_interceptor(Reject)
+        .with_interceptor(audit.clone())
+        .build()
+        .unwrap();
+
+    let response = client.assets().delete("1").await;
+
+    assert!(matches!(response, Err(Error::Interceptor(_))));
+    assert!(audit.0.lock().unwrap().is_empty());
+    assert_eq!(server.received_requests().await.unwrap().len(), 0);
+}
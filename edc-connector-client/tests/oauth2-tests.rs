@@ -0,0 +1,137 @@
+mod common;
+
+use edc_connector_client::Error;
+use reqwest::StatusCode;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_string_contains, header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use common::mock::{asset, setup_oauth2_client};
+
+fn token_response(token: &str, expires_in: u64) -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(json!({
+        "access_token": token,
+        "token_type": "Bearer",
+        "expires_in": expires_in
+    }))
+}
+
+#[tokio::test]
+async fn should_fetch_and_cache_the_access_token() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/token"))
+        .and(body_string_contains("grant_type=client_credentials"))
+        .and(body_string_contains("client_id=client"))
+        .and(body_string_contains("client_secret=secret"))
+        .and(body_string_contains("scope=management"))
+        .respond_with(token_response("token-1", 3600))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/management/v3/assets/1"))
+        .and(header("Authorization", "Bearer token-1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(asset("1")))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let client = setup_oauth2_client(&server);
+
+    client.assets().get("1").await.unwrap();
+    let asset = client.assets().get("1").await.unwrap();
+
+    assert_eq!(asset.id(), "1");
+}
+
+#[tokio::test]
+async fn should_refresh_the_access_token_before_expiry() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/token"))
+        .respond_with(token_response("token-1", 10))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/management/v3/assets/1"))
+        .and(header("Authorization", "Bearer token-1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(asset("1")))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let client = setup_oauth2_client(&server);
+
+    client.assets().get("1").await.unwrap();
+    client.assets().get("1").await.unwrap();
+}
+
+#[tokio::test]
+async fn should_retry_once_with_a_new_token_on_unauthorized() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/token"))
+        .respond_with(token_response("token-1", 3600))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/token"))
+        .respond_with(token_response("token-2", 3600))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/management/v3/assets/1"))
+        .and(header("Authorization", "Bearer token-1"))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/management/v3/assets/1"))
+        .and(header("Authorization", "Bearer token-2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(asset("1")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = setup_oauth2_client(&server);
+
+    let asset = client.assets().get("1").await.unwrap();
+
+    assert_eq!(asset.id(), "1");
+}
+
+#[tokio::test]
+async fn should_fail_when_the_token_endpoint_rejects_the_credentials() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/token"))
+        .respond_with(ResponseTemplate::new(400).set_body_string("invalid_client"))
+        .mount(&server)
+        .await;
+
+    let client = setup_oauth2_client(&server);
+
+    let response = client.assets().get("1").await;
+
+    assert!(matches!(
+        response,
+        Err(Error::TokenEndpoint(err)) if err.status_code == StatusCode::BAD_REQUEST && err.body == "invalid_client"
+    ))
+}
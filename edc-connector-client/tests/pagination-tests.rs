@@ -0,0 +1,249 @@
+mod common;
+
+use edc_connector_client::types::{catalog::CatalogRequest, query::Query};
+use futures::StreamExt;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_partial_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use common::mock::{asset, catalog, dataset, setup_client};
+
+fn policy_definition(id: &str) -> serde_json::Value {
+    json!({
+        "@context": { "@vocab": "https://w3id.org/edc/v0.0.1/ns/" },
+        "@id": id,
+        "policy": { "@type": "Set" }
+    })
+}
+
+async fn mount_page(
+    server: &MockServer,
+    url: &str,
+    offset: u32,
+    limit: u32,
+    page: Vec<serde_json::Value>,
+) {
+    Mock::given(method("POST"))
+        .and(path(url))
+        .and(body_partial_json(
+            json!({ "offset": offset, "limit": limit }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page))
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+async fn mount_catalog_page(server: &MockServer, offset: u32, page: serde_json::Value) {
+    Mock::given(method("POST"))
+        .and(path("/management/v3/catalog/request"))
+        .and(body_partial_json(
+            json!({ "querySpec": { "offset": offset, "limit": 2 } }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page))
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn should_stream_all_the_pages() {
+    let server = MockServer::start().await;
+    let url = "/management/v3/assets/request";
+
+    mount_page(&server, url, 0, 2, vec![asset("1"), asset("2")]).await;
+    mount_page(&server, url, 2, 2, vec![asset("3"), asset("4")]).await;
+    mount_page(&server, url, 4, 2, vec![asset("5")]).await;
+
+    let client = setup_client(&server);
+
+    let ids = client
+        .assets()
+        .query_stream(Query::builder().limit(2).build())
+        .map(|asset| asset.unwrap().id().to_string())
+        .collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(ids, vec!["1", "2", "3", "4", "5"]);
+}
+
+#[tokio::test]
+async fn should_stop_on_an_empty_page() {
+    let server = MockServer::start().await;
+    let url = "/management/v3/policydefinitions/request";
+
+    mount_page(
+        &server,
+        url,
+        10,
+        2,
+        vec![policy_definition("1"), policy_definition("2")],
+    )
+    .await;
+    mount_page(&server, url, 12, 2, vec![]).await;
+
+    let client = setup_client(&server);
+
+    let policies = client
+        .policies()
+        .query_all(Query::builder().offset(10).limit(2).build())
+        .await
+        .unwrap();
+
+    assert_eq!(policies.len(), 2);
+}
+
+#[tokio::test]
+async fn should_stop_on_the_first_error() {
+    let server = MockServer::start().await;
+    let url = "/management/v3/assets/request";
+
+    mount_page(&server, url, 0, 1, vec![asset("1")]).await;
+
+    Mock::given(method("POST"))
+        .and(path(url))
+        .and(body_partial_json(json!({ "offset": 1 })))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let client = setup_client(&server);
+
+    let results = client
+        .assets()
+        .query_stream(Query::builder().limit(1).build())
+        .collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(results.len(), 2);
+    assert!(results[0].is_ok());
+    assert!(results[1].is_err());
+
+    let all = client
+        .assets()
+        .query_all(Query::builder().limit(1).offset(1).build())
+        .await;
+
+    assert!(all.is_err());
+}
+
+#[tokio::test]
+async fn should_page_through_the_catalog() {
+    let server = MockServer::start().await;
+
+    // A page of two entries, one of them a sub-catalog without datasets
+    mount_catalog_page(
+        &server,
+        0,
+        catalog(
+            vec![dataset("1", "Dataset 1")],
+            vec![catalog(vec![], vec![])],
+        ),
+    )
+    .await;
+    mount_catalog_page(
+        &server,
+        2,
+        catalog(
+            vec![dataset("2", "Dataset 2")],
+            vec![catalog(
+                vec![dataset("3", "Dataset 3"), dataset("4", "Dataset 4")],
+                vec![],
+            )],
+        ),
+    )
+    .await;
+    mount_catalog_page(&server, 4, catalog(vec![dataset("5", "Dataset 5")], vec![])).await;
+
+    let client = setup_client(&server);
+
+    let request = CatalogRequest::builder()
+        .counter_party_address("http://provider/protocol")
+        .query_spec(Query::builder().limit(2).build())
+        .build()
+        .unwrap();
+
+    let ids = client
+        .catalogue()
+        .request_all(&request)
+        .await
+        .unwrap()
+        .iter()
+        .map(|dataset| dataset.id().to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(ids, vec!["1", "2", "3", "4", "5"]);
+}
+
+#[tokio::test]
+async fn should_stop_when_the_provider_ignores_the_offset() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/management/v3/catalog/request"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(catalog(
+            vec![dataset("1", "Dataset 1"), dataset("2", "Dataset 2")],
+            vec![],
+        )))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let client = setup_client(&server);
+
+    let request = CatalogRequest::builder()
+        .counter_party_address("http://provider/protocol")
+        .query_spec(Query::builder().limit(2).build())
+        .build()
+        .unwrap();
+
+    let ids = client
+        .catalogue()
+        .request_all(&request)
+        .await
+        .unwrap()
+        .iter()
+        .map(|dataset| dataset.id().to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(ids, vec!["1", "2"]);
+}
+
+#[tokio::test]
+async fn should_stop_on_a_catalog_page_without_new_datasets() {
+    let server = MockServer::start().await;
+
+    mount_catalog_page(
+        &server,
+        0,
+        catalog(
+            vec![dataset("1", "Dataset 1"), dataset("2", "Dataset 2")],
+            vec![],
+        ),
+    )
+    .await;
+    mount_catalog_page(
+        &server,
+        2,
+        catalog(
+            vec![dataset("2", "Dataset 2"), dataset("1", "Dataset 1")],
+            vec![],
+        ),
+    )
+    .await;
+
+    let client = setup_client(&server);
+
+    let request = CatalogRequest::builder()
+        .counter_party_address("http://provider/protocol")
+        .query_spec(Query::builder().limit(2).build())
+        .build()
+        .unwrap();
+
+    let datasets = client.catalogue().request_all(&request).await.unwrap();
+
+    assert_eq!(datasets.len(), 2);
+}
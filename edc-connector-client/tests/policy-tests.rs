@@ -264,3 +264,96 @@ mod query {
         assert_eq!(1, definitions.len());
     }
 }
+
+mod validate {
+    use edc_connector_client::{
+        types::policy::{NewPolicyDefinition, Policy},
+        Error, ManagementApiError, ManagementApiErrorDetailKind,
+    };
+    use reqwest::StatusCode;
+    use uuid::Uuid;
+
+    use crate::common::setup_provider_client;
+
+    #[tokio::test]
+    async fn should_validate_a_policy_definition() {
+        let client = setup_provider_client();
+        let id = Uuid::new_v4().to_string();
+
+        let policy_definition = NewPolicyDefinition::builder()
+            .id(&id)
+            .policy(Policy::builder().build())
+            .build();
+
+        client.policies().create(&policy_definition).await.unwrap();
+
+        let result = client.policies().validate(&id).await.unwrap();
+
+        assert!(result.is_valid());
+        assert!(result.errors().is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_fail_to_validate_a_policy_definition_when_not_existing() {
+        let client = setup_provider_client();
+        let id = Uuid::new_v4().to_string();
+
+        let response = client.policies().validate(&id).await;
+
+        assert!(matches!(
+            response,
+            Err(Error::ManagementApi(ManagementApiError {
+                status_code: StatusCode::NOT_FOUND,
+                error_detail: ManagementApiErrorDetailKind::Parsed(..)
+            }))
+        ))
+    }
+}
+
+mod evaluation_plan {
+    use edc_connector_client::types::policy::{
+        AtomicConstraint, Constraint, ConstraintStep, NewPolicyDefinition, Permission, Policy,
+    };
+    use uuid::Uuid;
+
+    use crate::common::setup_provider_client;
+
+    #[tokio::test]
+    async fn should_get_the_evaluation_plan_of_a_policy_definition() {
+        let client = setup_provider_client();
+        let id = Uuid::new_v4().to_string();
+
+        let policy = Policy::builder()
+            .permission(
+                Permission::builder()
+                    .constraint(Constraint::Atomic(AtomicConstraint::new(
+                        "foo", "eq", "bar",
+                    )))
+                    .build(),
+            )
+            .build();
+
+        let policy_definition = NewPolicyDefinition::builder()
+            .id(&id)
+            .policy(policy)
+            .build();
+
+        client.policies().create(&policy_definition).await.unwrap();
+
+        let plan = client
+            .policies()
+            .evaluation_plan(&id, "catalog")
+            .await
+            .unwrap();
+
+        assert_eq!(plan.permission_steps().len(), 1);
+
+        let step = &plan.permission_steps()[0];
+
+        assert_eq!(step.constraint_steps().len(), 1);
+        assert!(matches!(
+            step.constraint_steps()[0],
+            ConstraintStep::Atomic(..)
+        ));
+    }
+}
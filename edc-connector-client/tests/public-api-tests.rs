@@ -0,0 +1,181 @@
+mod common;
+
+use edc_connector_client::{types::data_address::DataAddress, DataPlaneError, Error};
+use futures::{stream, StreamExt};
+use reqwest::StatusCode;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_string, header, header_exists, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use common::mock::setup_api_key_client;
+
+fn edr(server: &MockServer) -> DataAddress {
+    DataAddress::builder()
+        .kind("https://w3id.org/idsa/v4.1/HTTP")
+        .property("endpoint", format!("{}/public", server.uri()))
+        .property("authType", "bearer")
+        .property("authorization", "edr-token")
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn should_get_data_with_the_edr_authorization() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/public/users/1"))
+        .and(query_param("fields", "name"))
+        .and(header("Authorization", "edr-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "name": "Leanne" })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = setup_api_key_client(&server);
+
+    let response = client
+        .public_api(&edr(&server))
+        .unwrap()
+        .get("/users/1")
+        .query(&[("fields", "name")])
+        .send()
+        .await
+        .unwrap();
+
+    let body = response.json::<serde_json::Value>().await.unwrap();
+
+    assert_eq!(body, json!({ "name": "Leanne" }));
+}
+
+#[tokio::test]
+async fn should_not_send_the_management_credentials() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/public"))
+        .and(header_exists("X-Api-Key"))
+        .respond_with(ResponseTemplate::new(400))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/public"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&server)
+        .await;
+
+    let response = setup_api_key_client(&server)
+        .public_api(&edr(&server))
+        .unwrap()
+        .get("")
+        .send()
+        .await;
+
+    assert!(response.is_ok());
+}
+
+#[tokio::test]
+async fn should_stream_request_and_response_bodies() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/public/upload"))
+        .and(body_string("first,second"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("stored"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let chunks = stream::iter(vec![Ok::<_, std::io::Error>("first,"), Ok("second")]);
+
+    let response = setup_api_key_client(&server)
+        .public_api(&edr(&server))
+        .unwrap()
+        .post("upload")
+        .body(reqwest::Body::wrap_stream(chunks))
+        .send()
+        .await
+        .unwrap();
+
+    let body = response
+        .bytes_stream()
+        .map(|chunk| chunk.unwrap().to_vec())
+        .concat()
+        .await;
+
+    assert_eq!(body, b"stored");
+}
+
+#[tokio::test]
+async fn should_report_data_plane_errors() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/public"))
+        .respond_with(ResponseTemplate::new(403).set_body_string("Token expired"))
+        .mount(&server)
+        .await;
+
+    let response = setup_api_key_client(&server)
+        .public_api(&edr(&server))
+        .unwrap()
+        .get("/")
+        .send()
+        .await;
+
+    assert!(matches!(
+        response,
+        Err(Error::DataPlane(DataPlaneError {
+            status_code: StatusCode::FORBIDDEN,
+            body
+        })) if body == "Token expired"
+    ));
+}
+
+#[tokio::test]
+async fn should_require_an_endpoint() {
+    let server = MockServer::start().await;
+    let data_address = DataAddress::builder()
+        .kind("https://w3id.org/idsa/v4.1/HTTP")
+        .build()
+        .unwrap();
+
+    let client = setup_api_key_client(&server);
+
+    assert!(matches!(
+        client.public_api(&data_address),
+        Err(Error::Builder(_))
+    ));
+}
+
+#[tokio::test]
+async fn should_reject_mistyped_edr_properties() {
+    let server = MockServer::start().await;
+    let client = setup_api_key_client(&server);
+
+    let mistyped_endpoint = DataAddress::builder()
+        .kind("https://w3id.org/idsa/v4.1/HTTP")
+        .property("endpoint", 8080)
+        .build()
+        .unwrap();
+
+    assert!(matches!(
+        client.public_api(&mistyped_endpoint),
+        Err(Error::Conversion(_))
+    ));
+
+    let mistyped_authorization = DataAddress::builder()
+        .kind("https://w3id.org/idsa/v4.1/HTTP")
+        .property("endpoint", format!("{}/public", server.uri()))
+        .property("authorization", json!({ "token": "edr-token" }))
+        .build()
+        .unwrap();
+
+    assert!(matches!(
+        client.public_api(&mistyped_authorization),
+        Err(Error::Conversion(_))
+    ));
+}
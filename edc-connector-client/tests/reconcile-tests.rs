@@ -0,0 +1,196 @@
+#![cfg(feature = "reconcile")]
+
+mod common;
+
+use edc_connector_client::reconcile::{Change, Manifest, ManifestError, ResourceKind};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use common::mock::{mount_write, setup_client, stored_asset};
+
+const MANIFEST: &str = r#"
+policyDefinitions:
+  - "@id": open-policy
+    policy:
+      "@type": Set
+assets:
+  - "@id": users
+    properties:
+      name: Users
+    dataAddress:
+      type: HttpData
+      baseUrl: https://jsonplaceholder.typicode.com/users
+contractDefinitions:
+  - "@id": users-offer
+    accessPolicyId: open-policy
+    contractPolicyId: open-policy
+    assetsSelector:
+      - operandLeft: https://w3id.org/edc/v0.0.1/ns/id
+        operator: "="
+        operandRight: users
+"#;
+
+async fn mount_connector(server: &MockServer) {
+    let context = json!({ "@vocab": "https://w3id.org/edc/v0.0.1/ns/" });
+
+    Mock::given(method("POST"))
+        .and(path("/management/v3/policydefinitions/request"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([{
+            "@context": context,
+            "@id": "open-policy",
+            "policy": { "@id": "d1a5b0a5-52c2-4b4f-9c5b-1e0e5f1c2a3b", "@type": "odrl:Set" }
+        }])))
+        .mount(server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/management/v3/assets/request"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            stored_asset("users", "Old users"),
+            stored_asset("stale", "Stale"),
+        ])))
+        .mount(server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/management/v3/contractdefinitions/request"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .mount(server)
+        .await;
+}
+
+#[test]
+fn should_load_a_yaml_manifest() {
+    let manifest = Manifest::from_yaml(MANIFEST).unwrap();
+
+    assert_eq!(manifest.assets().len(), 1);
+    assert_eq!(manifest.policy_definitions()[0].id(), Some("open-policy"));
+    assert_eq!(
+        manifest.contract_definitions()[0].access_policy_id(),
+        "open-policy"
+    );
+}
+
+#[test]
+fn should_reject_resources_without_or_with_duplicated_ids() {
+    let missing = Manifest::from_json(
+        r#"{ "assets": [{ "properties": {}, "dataAddress": { "type": "HttpData" } }] }"#,
+    );
+
+    assert!(matches!(
+        missing,
+        Err(ManifestError::MissingId {
+            kind: ResourceKind::Asset,
+            position: 0
+        })
+    ));
+
+    let duplicated = Manifest::from_json(
+        r#"{ "policyDefinitions": [
+            { "@id": "p", "policy": { "@type": "Set" } },
+            { "@id": "p", "policy": { "@type": "Set" } }
+        ] }"#,
+    );
+
+    assert!(matches!(
+        duplicated,
+        Err(ManifestError::DuplicateId { kind: ResourceKind::PolicyDefinition, id }) if id == "p"
+    ));
+}
+
+#[test]
+fn should_validate_manifests_deserialized_with_serde() {
+    let missing = serde_json::from_value::<Manifest>(json!({
+        "assets": [{ "properties": {}, "dataAddress": { "type": "HttpData" } }]
+    }));
+
+    assert!(missing
+        .unwrap_err()
+        .to_string()
+        .contains("The asset at position 0 has no @id"));
+}
+
+#[tokio::test]
+async fn should_plan_without_applying() {
+    let server = MockServer::start().await;
+
+    mount_connector(&server).await;
+    mount_write(&server, "PUT", "/management/v3/assets", 0).await;
+    mount_write(&server, "POST", "/management/v3/contractdefinitions", 0).await;
+
+    let manifest = Manifest::from_yaml(MANIFEST).unwrap();
+
+    let plan = setup_client(&server)
+        .reconcile(&manifest)
+        .plan()
+        .await
+        .unwrap();
+
+    assert_eq!(
+        plan.to_string(),
+        "~ asset users\n+ contract definition users-offer\n"
+    );
+}
+
+#[tokio::test]
+async fn should_prune_resources_missing_from_the_manifest() {
+    let server = MockServer::start().await;
+
+    mount_connector(&server).await;
+
+    let manifest = Manifest::from_yaml(MANIFEST).unwrap();
+
+    let plan = setup_client(&server)
+        .reconcile(&manifest)
+        .prune(true)
+        .plan()
+        .await
+        .unwrap();
+
+    let last = plan.actions().last().unwrap();
+
+    assert_eq!(last.kind(), ResourceKind::Asset);
+    assert_eq!(last.id(), "stale");
+    assert_eq!(last.change(), Change::Delete);
+}
+
+#[tokio::test]
+async fn should_apply_the_plan_in_dependency_order() {
+    let server = MockServer::start().await;
+
+    mount_connector(&server).await;
+    mount_write(&server, "PUT", "/management/v3/assets", 1).await;
+    mount_write(&server, "POST", "/management/v3/contractdefinitions", 1).await;
+    mount_write(&server, "DELETE", "/management/v3/assets/stale", 1).await;
+    mount_write(&server, "POST", "/management/v3/policydefinitions", 0).await;
+
+    let manifest = Manifest::from_yaml(MANIFEST).unwrap();
+
+    let plan = setup_client(&server)
+        .reconcile(&manifest)
+        .prune(true)
+        .apply()
+        .await
+        .unwrap();
+
+    assert_eq!(plan.actions().len(), 3);
+
+    let requests = server.received_requests().await.unwrap();
+    let writes = requests
+        .iter()
+        .filter(|request| !request.url.path().ends_with("/request"))
+        .map(|request| format!("{} {}", request.method, request.url.path()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        writes,
+        [
+            "PUT /management/v3/assets",
+            "POST /management/v3/contractdefinitions",
+            "DELETE /management/v3/assets/stale"
+        ]
+    );
+}
@@ -0,0 +1,137 @@
+mod common;
+
+use std::time::Duration;
+
+use edc_connector_client::{
+    types::{contract_negotiation::ContractRequest, policy::Policy, query::Query},
+    Error, ManagementApiError, RetryPolicy,
+};
+use reqwest::{Method, StatusCode};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use common::mock::{asset, setup_retry_client};
+
+fn retry_policy() -> RetryPolicy {
+    RetryPolicy::builder()
+        .max_attempts(3)
+        .initial_backoff(Duration::from_millis(10))
+        .build()
+}
+
+async fn mount_unavailable(server: &MockServer, http_method: &str, url: &str, times: u64) {
+    Mock::given(method(http_method))
+        .and(path(url))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(times)
+        .expect(times)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn should_retry_get_on_transient_status() {
+    let server = MockServer::start().await;
+
+    mount_unavailable(&server, "GET", "/management/v3/assets/1", 2).await;
+
+    Mock::given(method("GET"))
+        .and(path("/management/v3/assets/1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(asset("1")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = setup_retry_client(&server, retry_policy());
+
+    let asset = client.assets().get("1").await.unwrap();
+
+    assert_eq!(asset.id(), "1");
+}
+
+#[tokio::test]
+async fn should_give_up_after_max_attempts() {
+    let server = MockServer::start().await;
+
+    mount_unavailable(&server, "DELETE", "/management/v3/assets/1", 3).await;
+
+    let client = setup_retry_client(&server, retry_policy());
+
+    let response = client.assets().delete("1").await;
+
+    assert!(matches!(
+        response,
+        Err(Error::ManagementApi(ManagementApiError {
+            status_code: StatusCode::SERVICE_UNAVAILABLE,
+            ..
+        }))
+    ));
+}
+
+#[tokio::test]
+async fn should_retry_read_only_posts() {
+    let server = MockServer::start().await;
+
+    mount_unavailable(&server, "POST", "/management/v3/assets/request", 1).await;
+
+    Mock::given(method("POST"))
+        .and(path("/management/v3/assets/request"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([asset("1")])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = setup_retry_client(&server, retry_policy());
+
+    let assets = client.assets().query(Query::default()).await.unwrap();
+
+    assert_eq!(assets.len(), 1);
+}
+
+#[tokio::test]
+async fn should_not_replay_state_changing_posts() {
+    let server = MockServer::start().await;
+
+    mount_unavailable(&server, "POST", "/management/v3/contractnegotiations", 1).await;
+
+    let client = setup_retry_client(&server, retry_policy());
+
+    let request = ContractRequest::builder()
+        .counter_party_address("http://provider")
+        .counter_party_id("provider")
+        .policy(Policy::builder().build())
+        .build()
+        .unwrap();
+
+    let response = client.contract_negotiations().initiate(&request).await;
+
+    assert!(matches!(
+        response,
+        Err(Error::ManagementApi(ManagementApiError {
+            status_code: StatusCode::SERVICE_UNAVAILABLE,
+            ..
+        }))
+    ));
+}
+
+#[tokio::test]
+async fn should_not_retry_when_method_is_not_retryable() {
+    let server = MockServer::start().await;
+
+    mount_unavailable(&server, "GET", "/management/v3/assets/1", 1).await;
+
+    let client = setup_retry_client(
+        &server,
+        RetryPolicy::builder()
+            .initial_backoff(Duration::from_millis(10))
+            .retryable_methods(vec![Method::PUT])
+            .build(),
+    );
+
+    let response = client.assets().get("1").await;
+
+    assert!(response.is_err());
+}
@@ -0,0 +1,176 @@
+mod common;
+
+mod create {
+    use edc_connector_client::{
+        types::secret::NewSecret, Error, ManagementApiError, ManagementApiErrorDetailKind,
+    };
+    use reqwest::StatusCode;
+    use uuid::Uuid;
+
+    use crate::common::setup_provider_client;
+
+    #[tokio::test]
+    async fn should_create_a_secret() {
+        let client = setup_provider_client();
+
+        let id = Uuid::new_v4().to_string();
+
+        let secret = NewSecret::builder()
+            .id(&id)
+            .value("secret-value")
+            .build()
+            .unwrap();
+
+        let response = client.secrets().create(&secret).await.unwrap();
+
+        assert_eq!(&id, response.id());
+        assert!(response.created_at() > 0);
+    }
+
+    #[tokio::test]
+    async fn should_fail_to_create_a_secret_when_existing() {
+        let client = setup_provider_client();
+
+        let id = Uuid::new_v4().to_string();
+
+        let secret = NewSecret::builder()
+            .id(&id)
+            .value("secret-value")
+            .build()
+            .unwrap();
+
+        client.secrets().create(&secret).await.unwrap();
+
+        let response = client.secrets().create(&secret).await;
+
+        assert!(matches!(
+            response,
+            Err(Error::ManagementApi(ManagementApiError {
+                status_code: StatusCode::CONFLICT,
+                error_detail: ManagementApiErrorDetailKind::Parsed(..)
+            }))
+        ))
+    }
+}
+
+mod delete {
+    use edc_connector_client::{
+        types::secret::NewSecret, Error, ManagementApiError, ManagementApiErrorDetailKind,
+    };
+    use reqwest::StatusCode;
+    use uuid::Uuid;
+
+    use crate::common::setup_provider_client;
+
+    #[tokio::test]
+    async fn should_delete_a_secret() {
+        let client = setup_provider_client();
+        let id = Uuid::new_v4().to_string();
+
+        let secret = NewSecret::builder()
+            .id(&id)
+            .value("secret-value")
+            .build()
+            .unwrap();
+
+        client.secrets().create(&secret).await.unwrap();
+
+        let response = client.secrets().delete(&id).await;
+
+        assert!(response.is_ok());
+    }
+
+    #[tokio::test]
+    async fn should_fail_to_delete_a_secret_when_not_existing() {
+        let client = setup_provider_client();
+        let id = Uuid::new_v4().to_string();
+
+        let response = client.secrets().delete(&id).await;
+
+        assert!(matches!(
+            response,
+            Err(Error::ManagementApi(ManagementApiError {
+                status_code: StatusCode::NOT_FOUND,
+                error_detail: ManagementApiErrorDetailKind::Parsed(..)
+            }))
+        ))
+    }
+}
+
+mod get {
+    use edc_connector_client::{
+        types::secret::NewSecret, Error, ManagementApiError, ManagementApiErrorDetailKind,
+    };
+    use reqwest::StatusCode;
+    use uuid::Uuid;
+
+    use crate::common::setup_provider_client;
+
+    #[tokio::test]
+    async fn should_get_a_secret() {
+        let client = setup_provider_client();
+        let id = Uuid::new_v4().to_string();
+
+        let secret = NewSecret::builder()
+            .id(&id)
+            .value("secret-value")
+            .build()
+            .unwrap();
+
+        client.secrets().create(&secret).await.unwrap();
+
+        let secret = client.secrets().get(&id).await.unwrap();
+
+        assert_eq!(&id, secret.id());
+        assert_eq!("secret-value", secret.value());
+    }
+
+    #[tokio::test]
+    async fn should_fail_to_get_a_secret_when_not_existing() {
+        let client = setup_provider_client();
+        let id = Uuid::new_v4().to_string();
+
+        let response = client.secrets().get(&id).await;
+
+        assert!(matches!(
+            response,
+            Err(Error::ManagementApi(ManagementApiError {
+                status_code: StatusCode::NOT_FOUND,
+                error_detail: ManagementApiErrorDetailKind::Parsed(..)
+            }))
+        ))
+    }
+}
+
+mod update {
+    use edc_connector_client::types::secret::{NewSecret, Secret};
+    use uuid::Uuid;
+
+    use crate::common::setup_provider_client;
+
+    #[tokio::test]
+    async fn should_update_a_secret() {
+        let client = setup_provider_client();
+        let id = Uuid::new_v4().to_string();
+
+        let secret = NewSecret::builder()
+            .id(&id)
+            .value("secret-value")
+            .build()
+            .unwrap();
+
+        client.secrets().create(&secret).await.unwrap();
+
+        let secret = Secret::builder()
+            .id(&id)
+            .value("updated-value")
+            .build()
+            .unwrap();
+
+        client.secrets().update(&secret).await.unwrap();
+
+        let secret = client.secrets().get(&id).await.unwrap();
+
+        assert_eq!("updated-value", secret.value());
+    }
+}
@@ -0,0 +1,184 @@
+mod common;
+
+use std::{collections::VecDeque, sync::Mutex};
+
+use edc_connector_client::{
+    types::{
+        contract_agreement::ContractAgreement,
+        contract_negotiation::{ContractNegotiation, ContractNegotiationState, ContractRequest},
+        query::Query,
+        response::IdResponse,
+    },
+    AgreementStore, AssetStore, EdcResult, Error, NegotiationService, WorkflowError,
+};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_partial_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use common::mock::{asset, negotiation, setup_client, wait_options};
+
+fn agreement(id: usize) -> ContractAgreement {
+    serde_json::from_value(json!({
+        "@id": id.to_string(),
+        "assetId": "asset-id",
+        "consumerId": "consumer",
+        "providerId": "provider",
+        "contractSigningDate": 0,
+        "policy": { "@type": "Agreement" }
+    }))
+    .unwrap()
+}
+
+/// Negotiation service answering `get_negotiation` with the scripted states.
+struct ScriptedNegotiations(Mutex<VecDeque<&'static str>>);
+
+impl ScriptedNegotiations {
+    fn new(states: &[&'static str]) -> Self {
+        ScriptedNegotiations(Mutex::new(states.iter().copied().collect()))
+    }
+}
+
+#[async_trait::async_trait]
+impl NegotiationService for ScriptedNegotiations {
+    async fn initiate_negotiation(&self, _: &ContractRequest) -> EdcResult<IdResponse<String>> {
+        unimplemented!()
+    }
+
+    async fn get_negotiation(&self, _: &str) -> EdcResult<ContractNegotiation> {
+        let mut states = self.0.lock().unwrap();
+        let state = if states.len() > 1 {
+            states.pop_front()
+        } else {
+            states.front().copied()
+        };
+        Ok(serde_json::from_value(negotiation(state.unwrap())).unwrap())
+    }
+
+    async fn get_negotiation_state(&self, _: &str) -> EdcResult<ContractNegotiationState> {
+        unimplemented!()
+    }
+
+    async fn get_negotiation_agreement(&self, _: &str) -> EdcResult<ContractAgreement> {
+        unimplemented!()
+    }
+
+    async fn terminate_negotiation(&self, _: &str, _: &str) -> EdcResult<()> {
+        unimplemented!()
+    }
+
+    async fn query_negotiations(&self, _: Query) -> EdcResult<Vec<ContractNegotiation>> {
+        unimplemented!()
+    }
+}
+
+/// Agreement store paging over agreements kept in memory.
+struct InMemoryAgreements(Vec<ContractAgreement>);
+
+#[async_trait::async_trait]
+impl AgreementStore for InMemoryAgreements {
+    async fn get_agreement(&self, _: &str) -> EdcResult<ContractAgreement> {
+        unimplemented!()
+    }
+
+    async fn get_agreement_negotiation(&self, _: &str) -> EdcResult<ContractNegotiation> {
+        unimplemented!()
+    }
+
+    async fn query_agreements(&self, query: Query) -> EdcResult<Vec<ContractAgreement>> {
+        Ok(self
+            .0
+            .iter()
+            .skip(query.offset() as usize)
+            .take(query.limit() as usize)
+            .cloned()
+            .collect())
+    }
+}
+
+#[tokio::test]
+async fn should_use_the_client_as_asset_store() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/management/v3/assets/1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(asset("1")))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/management/v3/assets/request"))
+        .and(body_partial_json(json!({ "offset": 0, "limit": 2 })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(vec![asset("1"), asset("2")]))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/management/v3/assets/request"))
+        .and(body_partial_json(json!({ "offset": 2, "limit": 2 })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(vec![asset("3")]))
+        .mount(&server)
+        .await;
+
+    let store: Box<dyn AssetStore> = Box::new(setup_client(&server));
+
+    assert_eq!(store.get_asset("1").await.unwrap().id(), "1");
+
+    let ids = store
+        .query_all_assets(Query::builder().limit(2).build())
+        .await
+        .unwrap()
+        .iter()
+        .map(|asset| asset.id().to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(ids, ["1", "2", "3"]);
+}
+
+#[tokio::test]
+async fn should_wait_for_the_state_of_a_test_double() {
+    let negotiations = ScriptedNegotiations::new(&["REQUESTED", "AGREED", "FINALIZED"]);
+
+    let negotiation = negotiations
+        .wait_for_negotiation_state(
+            "negotiation-id",
+            ContractNegotiationState::Finalized,
+            &wait_options(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(negotiation.state(), &ContractNegotiationState::Finalized);
+}
+
+#[tokio::test]
+async fn should_fail_the_wait_when_a_test_double_terminates() {
+    let negotiations = ScriptedNegotiations::new(&["REQUESTED", "TERMINATED"]);
+
+    let err = negotiations
+        .wait_for_negotiation_state(
+            "negotiation-id",
+            ContractNegotiationState::Finalized,
+            &wait_options(),
+        )
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        err,
+        Error::Workflow(WorkflowError::NegotiationTerminated { .. })
+    ));
+}
+
+#[tokio::test]
+async fn should_page_through_a_test_double() {
+    let agreements = InMemoryAgreements((0..5).map(agreement).collect());
+
+    let all = agreements
+        .query_all_agreements(Query::builder().limit(2).build())
+        .await
+        .unwrap();
+
+    assert_eq!(all.len(), 5);
+}
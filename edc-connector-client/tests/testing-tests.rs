@@ -0,0 +1,186 @@
+#![cfg(feature = "testing")]
+
+mod common;
+
+use edc_connector_client::{
+    testing::FakeConnector,
+    types::{
+        contract_negotiation::{ContractNegotiationState, ContractRequest},
+        policy::{Policy, PolicyKind, Target},
+        query::{Query, SortOrder},
+        transfer_process::{TransferProcessState, TransferRequest},
+    },
+    EdcConnectorClient, UpsertOutcome,
+};
+
+use common::mock::{new_asset, wait_options};
+
+async fn agree(client: &EdcConnectorClient) -> String {
+    let request = ContractRequest::builder()
+        .counter_party_address("http://provider:19194/protocol")
+        .counter_party_id("provider")
+        .policy(
+            Policy::builder()
+                .id("offer-1")
+                .kind(PolicyKind::Offer)
+                .assigner("provider")
+                .target(Target::id("users"))
+                .build(),
+        )
+        .build()
+        .unwrap();
+
+    let negotiations = client.contract_negotiations();
+    let id = negotiations.initiate(&request).await.unwrap();
+
+    negotiations
+        .wait_for_state(
+            id.id(),
+            ContractNegotiationState::Finalized,
+            &wait_options(),
+        )
+        .await
+        .unwrap()
+        .contract_agreement_id()
+        .cloned()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn should_store_and_query_assets() {
+    let connector = FakeConnector::start().await.unwrap();
+    let client = connector.client().unwrap();
+    let assets = client.assets();
+
+    for (id, name) in [("1", "users"), ("2", "orders"), ("3", "user groups")] {
+        assets.create(&new_asset(id, name)).await.unwrap();
+    }
+
+    let err = assets.create(&new_asset("1", "users")).await.unwrap_err();
+    assert!(err.is_conflict());
+
+    let query = Query::builder()
+        .filter("name", "like", "user%")
+        .sort("name", SortOrder::Desc)
+        .build();
+
+    let found = assets.query(query).await.unwrap();
+    let ids = found.iter().map(|asset| asset.id()).collect::<Vec<_>>();
+
+    assert_eq!(ids, ["1", "3"]);
+    assert_eq!(
+        assets.upsert(&new_asset("2", "orders")).await.unwrap(),
+        UpsertOutcome::Unchanged
+    );
+
+    assets.delete("2").await.unwrap();
+    assert!(assets.get("2").await.unwrap_err().is_not_found());
+}
+
+#[tokio::test]
+async fn should_negotiate_and_transfer() {
+    let connector = FakeConnector::builder()
+        .participant_id("consumer")
+        .public_endpoint("http://dataplane/public")
+        .start()
+        .await
+        .unwrap();
+    let client = connector.client().unwrap();
+
+    let agreement_id = agree(&client).await;
+    let agreement = client
+        .contract_agreements()
+        .get(&agreement_id)
+        .await
+        .unwrap();
+
+    assert_eq!(agreement.asset_id(), "users");
+    assert_eq!(agreement.provider_id(), "provider");
+    assert_eq!(agreement.consumer_id(), "consumer");
+
+    let request = TransferRequest::builder()
+        .counter_party_address("http://provider:19194/protocol")
+        .contract_id(&agreement_id)
+        .transfer_type("HttpData-PULL")
+        .build()
+        .unwrap();
+
+    let transfers = client.transfer_processes();
+    let id = transfers.initiate(&request).await.unwrap();
+    let transfer = transfers
+        .wait_for_state(id.id(), TransferProcessState::Started, &wait_options())
+        .await
+        .unwrap();
+
+    assert_eq!(transfer.asset_id(), "users");
+
+    let data_address = client.edrs().get_data_address(id.id()).await.unwrap();
+
+    assert_eq!(
+        data_address.property::<String>("endpoint").unwrap(),
+        Some("http://dataplane/public".to_string())
+    );
+    assert_eq!(
+        client
+            .edrs()
+            .get_entry(id.id())
+            .await
+            .unwrap()
+            .agreement_id(),
+        agreement_id
+    );
+
+    transfers.terminate(id.id(), "done").await.unwrap();
+    assert_eq!(
+        transfers.get_state(id.id()).await.unwrap(),
+        TransferProcessState::Terminated
+    );
+}
+
+#[tokio::test]
+async fn should_terminate_transfers_without_agreement() {
+    let connector = FakeConnector::start().await.unwrap();
+    let client = connector.client().unwrap();
+
+    let request = TransferRequest::builder()
+        .counter_party_address("http://provider:19194/protocol")
+        .contract_id("unknown")
+        .transfer_type("HttpData-PULL")
+        .build()
+        .unwrap();
+
+    let transfers = client.transfer_processes();
+    let id = transfers.initiate(&request).await.unwrap();
+    let transfer = transfers
+        .wait_for_state(id.id(), TransferProcessState::Terminated, &wait_options())
+        .await
+        .unwrap();
+
+    assert!(transfer.error_detail().is_some());
+}
+
+#[tokio::test]
+async fn should_require_the_api_key() {
+    let connector = FakeConnector::builder()
+        .api_key("secret")
+        .start()
+        .await
+        .unwrap();
+
+    let unauthenticated = EdcConnectorClient::builder()
+        .management_url(connector.management_url())
+        .build()
+        .unwrap();
+
+    let err = unauthenticated.assets().get("1").await.unwrap_err();
+    assert!(err.is_unauthorized());
+
+    let err = connector
+        .client()
+        .unwrap()
+        .assets()
+        .get("1")
+        .await
+        .unwrap_err();
+    assert!(err.is_not_found());
+}
@@ -0,0 +1,182 @@
+mod common;
+
+use edc_connector_client::{
+    types::{
+        asset::NewAsset,
+        contract_definition::NewContractDefinition,
+        data_address::DataAddress,
+        policy::{NewPolicyDefinition, Policy},
+    },
+    Error, UpsertOutcome,
+};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use common::mock::{mount_write, new_asset, setup_client, stored_asset};
+
+#[tokio::test]
+async fn should_create_a_missing_asset() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/management/v3/assets/1"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!([{
+            "message": "Object of type Asset with ID=1 was not found",
+            "type": "ObjectNotFound"
+        }])))
+        .mount(&server)
+        .await;
+
+    mount_write(&server, "POST", "/management/v3/assets", 1).await;
+    mount_write(&server, "PUT", "/management/v3/assets", 0).await;
+
+    let outcome = setup_client(&server)
+        .assets()
+        .upsert(&new_asset("1", "report"))
+        .await
+        .unwrap();
+
+    assert_eq!(outcome, UpsertOutcome::Created);
+}
+
+#[tokio::test]
+async fn should_update_a_changed_asset() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/management/v3/assets/1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(stored_asset("1", "draft")))
+        .mount(&server)
+        .await;
+
+    mount_write(&server, "POST", "/management/v3/assets", 0).await;
+    mount_write(&server, "PUT", "/management/v3/assets", 1).await;
+
+    let outcome = setup_client(&server)
+        .assets()
+        .upsert(&new_asset("1", "report"))
+        .await
+        .unwrap();
+
+    assert_eq!(outcome, UpsertOutcome::Updated);
+}
+
+#[tokio::test]
+async fn should_leave_an_unchanged_asset() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/management/v3/assets/1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(stored_asset("1", "report")))
+        .mount(&server)
+        .await;
+
+    mount_write(&server, "POST", "/management/v3/assets", 0).await;
+    mount_write(&server, "PUT", "/management/v3/assets", 0).await;
+
+    let outcome = setup_client(&server)
+        .assets()
+        .upsert(&new_asset("1", "report"))
+        .await
+        .unwrap();
+
+    assert_eq!(outcome, UpsertOutcome::Unchanged);
+}
+
+#[tokio::test]
+async fn should_require_an_id() {
+    let server = MockServer::start().await;
+
+    let asset = NewAsset::builder()
+        .data_address(DataAddress::builder().kind("HttpData").build().unwrap())
+        .build()
+        .unwrap();
+
+    let result = setup_client(&server).assets().upsert(&asset).await;
+
+    assert!(matches!(result, Err(Error::Builder(_))));
+}
+
+#[tokio::test]
+async fn should_leave_an_unchanged_policy_definition() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/management/v3/policydefinitions/policy-1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "@context": {
+                "@vocab": "https://w3id.org/edc/v0.0.1/ns/",
+                "odrl": "http://www.w3.org/ns/odrl/2/"
+            },
+            "@id": "policy-1",
+            "@type": "PolicyDefinition",
+            "policy": {
+                "@id": "0a7a7bd9-d8a6-4ee1-a4a5-2b0d2b4e6a5f",
+                "@type": "odrl:Set",
+                "odrl:permission": [],
+                "odrl:prohibition": [],
+                "odrl:obligation": []
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    mount_write(
+        &server,
+        "PUT",
+        "/management/v2/policydefinitions/policy-1",
+        0,
+    )
+    .await;
+
+    let policy_definition = NewPolicyDefinition::builder()
+        .id("policy-1")
+        .policy(Policy::builder().build())
+        .build();
+
+    let outcome = setup_client(&server)
+        .policies()
+        .upsert(&policy_definition)
+        .await
+        .unwrap();
+
+    assert_eq!(outcome, UpsertOutcome::Unchanged);
+}
+
+#[tokio::test]
+async fn should_update_a_changed_contract_definition() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/management/v3/contractdefinitions/definition-1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "@context": { "@vocab": "https://w3id.org/edc/v0.0.1/ns/" },
+            "@id": "definition-1",
+            "@type": "ContractDefinition",
+            "accessPolicyId": "policy-1",
+            "contractPolicyId": "policy-1",
+            "assetsSelector": []
+        })))
+        .mount(&server)
+        .await;
+
+    mount_write(&server, "PUT", "/management/v3/contractdefinitions", 1).await;
+
+    let contract_definition = NewContractDefinition::builder()
+        .id("definition-1")
+        .access_policy_id("policy-1")
+        .contract_policy_id("policy-2")
+        .build()
+        .unwrap();
+
+    let outcome = setup_client(&server)
+        .contract_definitions()
+        .upsert(&contract_definition)
+        .await
+        .unwrap();
+
+    assert_eq!(outcome, UpsertOutcome::Updated);
+}
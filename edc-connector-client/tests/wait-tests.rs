@@ -0,0 +1,144 @@
+mod common;
+
+use std::time::Duration;
+
+use edc_connector_client::{
+    types::{
+        contract_negotiation::ContractNegotiationState, transfer_process::TransferProcessState,
+    },
+    Error, WaitOptions, WorkflowError,
+};
+use futures::StreamExt;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use common::mock::{negotiation, setup_client, transfer_process, wait_options};
+
+/// Mounts the given responses, each one served the given number of times in order.
+async fn mount_states(server: &MockServer, url: &str, states: Vec<(serde_json::Value, u64)>) {
+    for (priority, (body, times)) in states.into_iter().enumerate() {
+        Mock::given(method("GET"))
+            .and(path(url))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .up_to_n_times(times)
+            .with_priority(priority as u8 + 1)
+            .mount(server)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn should_wait_for_the_target_state() {
+    let server = MockServer::start().await;
+    mount_states(
+        &server,
+        "/management/v3/contractnegotiations/negotiation-id",
+        vec![
+            (negotiation("REQUESTED"), 2),
+            (negotiation("AGREED"), 1),
+            (negotiation("FINALIZED"), 10),
+        ],
+    )
+    .await;
+
+    let negotiation = setup_client(&server)
+        .contract_negotiations()
+        .wait_for_state(
+            "negotiation-id",
+            ContractNegotiationState::Finalized,
+            &wait_options(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(negotiation.state(), &ContractNegotiationState::Finalized);
+}
+
+#[tokio::test]
+async fn should_fail_fast_on_terminated() {
+    let server = MockServer::start().await;
+    mount_states(
+        &server,
+        "/management/v3/transferprocesses/transfer-id",
+        vec![
+            (transfer_process("REQUESTED"), 1),
+            (transfer_process("TERMINATED"), 10),
+        ],
+    )
+    .await;
+
+    let result = setup_client(&server)
+        .transfer_processes()
+        .wait_for_state(
+            "transfer-id",
+            TransferProcessState::Started,
+            &wait_options(),
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(Error::Workflow(WorkflowError::TransferTerminated { id, .. })) if id == "transfer-id"
+    ));
+}
+
+#[tokio::test]
+async fn should_time_out_waiting_for_a_state() {
+    let server = MockServer::start().await;
+    mount_states(
+        &server,
+        "/management/v3/transferprocesses/transfer-id",
+        vec![(transfer_process("REQUESTED"), 100)],
+    )
+    .await;
+
+    let options = WaitOptions::builder()
+        .interval(Duration::from_millis(10))
+        .timeout(Duration::from_millis(100))
+        .build();
+
+    let result = setup_client(&server)
+        .transfer_processes()
+        .wait_for_state("transfer-id", TransferProcessState::Started, &options)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(Error::Workflow(WorkflowError::Timeout(_)))
+    ));
+}
+
+#[tokio::test]
+async fn should_watch_every_state_transition() {
+    let server = MockServer::start().await;
+    mount_states(
+        &server,
+        "/management/v3/transferprocesses/transfer-id",
+        vec![
+            (transfer_process("REQUESTED"), 2),
+            (transfer_process("STARTED"), 3),
+            (transfer_process("COMPLETED"), 10),
+        ],
+    )
+    .await;
+
+    let client = setup_client(&server);
+
+    let states = client
+        .transfer_processes()
+        .watch("transfer-id", &wait_options())
+        .map(|transfer_process| transfer_process.unwrap().state().clone())
+        .collect::<Vec<_>>()
+        .await;
+
+    assert_eq!(
+        states,
+        vec![
+            TransferProcessState::Requested,
+            TransferProcessState::Started,
+            TransferProcessState::Completed
+        ]
+    );
+}